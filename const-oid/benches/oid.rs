@@ -0,0 +1,104 @@
+use const_oid::{ObjectIdentifier, ObjectIdentifierRef};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+/// `1.2.840.113549.1.1.11` (sha256WithRSAEncryption)
+const SHORT_OID_BER: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0b];
+
+/// `0.9.2342.19200300.100.1.1.4294967295.4294967295.4294967295`
+const LONG_OID_BER: &[u8] = &[
+    0x09, 0x92, 0x26, 0x89, 0x93, 0xf2, 0x2c, 0x64, 0x01, 0x01, 0x8f, 0xff, 0xff, 0xff, 0x7f, 0x8f,
+    0xff, 0xff, 0xff, 0x7f, 0x8f, 0xff, 0xff, 0xff, 0x7f,
+];
+
+fn from_bytes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("from_bytes");
+
+    group.bench_function("short", |b| {
+        b.iter(|| ObjectIdentifier::from_bytes(black_box(SHORT_OID_BER)))
+    });
+
+    group.bench_function("long", |b| {
+        b.iter(|| ObjectIdentifier::from_bytes(black_box(LONG_OID_BER)))
+    });
+
+    group.bench_function("ref_long", |b| {
+        b.iter(|| ObjectIdentifierRef::from_bytes(black_box(LONG_OID_BER)).is_ok())
+    });
+
+    group.finish();
+}
+
+fn starts_with(c: &mut Criterion) {
+    let mut group = c.benchmark_group("starts_with");
+    let short = ObjectIdentifier::from_bytes(SHORT_OID_BER).unwrap();
+    let long = ObjectIdentifier::from_bytes(LONG_OID_BER).unwrap();
+
+    // All but the last arc of the long OID
+    let long_prefix = long.parent().unwrap();
+    let short_prefix = ObjectIdentifier::new_unwrap("0.9.2342");
+    let short_parent = short.parent().unwrap();
+
+    group.bench_function("short", |b| {
+        b.iter(|| black_box(&short).starts_with(black_box(short_parent)))
+    });
+
+    group.bench_function("long_short_prefix", |b| {
+        b.iter(|| black_box(&long).starts_with(black_box(short_prefix)))
+    });
+
+    group.bench_function("long_long_prefix", |b| {
+        b.iter(|| black_box(&long).starts_with(black_box(long_prefix)))
+    });
+
+    group.finish();
+}
+
+fn arcs(c: &mut Criterion) {
+    let mut group = c.benchmark_group("arcs");
+    let short = ObjectIdentifierRef::from_bytes(SHORT_OID_BER).unwrap();
+    let long = ObjectIdentifierRef::from_bytes(LONG_OID_BER).unwrap();
+
+    group.bench_function("short", |b| {
+        b.iter(|| {
+            black_box(short)
+                .arcs()
+                .fold(0u64, |acc, arc| acc + u64::from(arc))
+        })
+    });
+
+    group.bench_function("long", |b| {
+        b.iter(|| {
+            black_box(long)
+                .arcs()
+                .fold(0u64, |acc, arc| acc + u64::from(arc))
+        })
+    });
+
+    group.finish();
+}
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn hash(c: &mut Criterion) {
+    let mut group = c.benchmark_group("hash");
+    let oid = ObjectIdentifier::from_bytes(SHORT_OID_BER).unwrap();
+
+    // `Buffer` still uses the derived `Hash`, which covers the entire backing array
+    let buffer = oid.canonical_bytes();
+
+    group.bench_function("oid", |b| b.iter(|| hash_of(black_box(&oid))));
+    group.bench_function("derived", |b| b.iter(|| hash_of(black_box(&buffer))));
+
+    group.finish();
+}
+
+criterion_group!(benches, from_bytes, starts_with, arcs, hash);
+criterion_main!(benches);
@@ -46,7 +46,12 @@ impl Asn1Parser {
     ";
 
     pub fn new(asn1: &str, bases: &[(&'static str, &'static str)]) -> Self {
-        let def = Regex::new(Self::DEF).unwrap();
+        Self::with_def(asn1, Self::DEF, bases)
+    }
+
+    // `def` must provide the same `name`, `base` and `tail` groups as `DEF`.
+    pub(crate) fn with_def(text: &str, def: &str, bases: &[(&'static str, &'static str)]) -> Self {
+        let def = Regex::new(def).unwrap();
         let arc = Regex::new(Self::ARC).unwrap();
 
         let mut base = BTreeMap::default();
@@ -55,7 +60,7 @@ impl Asn1Parser {
         }
 
         let mut tree = BTreeMap::default();
-        for mat in def.find_iter(asn1) {
+        for mat in def.find_iter(text) {
             let caps = def.captures(mat.as_str()).unwrap();
             let name = caps.name("name").unwrap().as_str().trim().to_string();
             let base = caps.name("base").map(|m| m.as_str().trim().to_string());
@@ -85,6 +90,14 @@ impl Asn1Parser {
             return Some(tail.to_string());
         }
 
+        // Well-known roots, e.g. `{ iso 3 6 1 }`
+        match name {
+            "itu-t" | "ccitt" => return Some("0".to_string()),
+            "iso" => return Some("1".to_string()),
+            "joint-iso-itu-t" | "joint-iso-ccitt" => return Some("2".to_string()),
+            _ => (),
+        }
+
         let (base, arcs) = self.tree.get(name)?;
         if let Some(base) = base {
             let base = self.resolve(base)?;
@@ -113,11 +126,32 @@ fn test() {
             bat OBJECT IDENTIFIER ::= { foo qux(4) 5 }
             quz OBJECT IDENTIFIER ::= { bat 6 }
         ",
+        &[],
+    );
+
+    let answers: Vec<_> = asn1.iter().collect();
+    assert_eq!(
+        answers,
+        [
+            ("bat".to_string(), "1.2.3.4.5".to_string()),
+            ("foo".to_string(), "1.2.3".to_string()),
+            ("quz".to_string(), "1.2.3.4.5.6".to_string()),
+        ]
     );
+}
 
-    let answer = ("quz".to_string(), "1.2.3.4.5.6".to_string());
+#[test]
+fn test_well_known_roots() {
+    let asn1 = super::Asn1Parser::new(
+        r"
+            internet OBJECT IDENTIFIER ::= { iso 3 6 1 }
+            data OBJECT IDENTIFIER ::= { ccitt 9 }
+            ds OBJECT IDENTIFIER ::= { joint-iso-ccitt 5 }
+        ",
+        &[],
+    );
 
-    let mut iter = asn1.iter();
-    assert_eq!(Some(answer), iter.next());
-    assert_eq!(None, iter.next());
+    assert_eq!(asn1.resolve("internet").as_deref(), Some("1.3.6.1"));
+    assert_eq!(asn1.resolve("data").as_deref(), Some("0.9"));
+    assert_eq!(asn1.resolve("ds").as_deref(), Some("2.5"));
 }
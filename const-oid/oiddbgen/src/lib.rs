@@ -1,9 +1,11 @@
 mod asn1;
 mod ldap;
+mod mib;
 mod node;
 mod root;
 mod spec;
 
 pub use asn1::Asn1Parser;
 pub use ldap::LdapParser;
+pub use mib::MibParser;
 pub use root::Root;
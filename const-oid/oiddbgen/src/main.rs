@@ -24,9 +24,114 @@ const MDS: &[(&str, &str)] = &[
     // Created from:
     // https://csrc.nist.gov/projects/computer-security-objects-register/algorithm-registration
     ("fips202", include_str!("../fips202.md")),
+    ("pkcs9", include_str!("../pkcs9.md")),
     ("rfc8894", include_str!("../rfc8894.md")),
 ];
 
+// Composite signature algorithms: (signature, digest, public key).
+//
+// EdDSA signatures use the public key OID itself, along with the digest
+// mandated by RFC 8032.
+const SIGNATURES: &[(&str, &str, &str)] = &[
+    ("sha1WithRSAEncryption", "id-sha1", "rsaEncryption"),
+    ("sha224WithRSAEncryption", "id-sha224", "rsaEncryption"),
+    ("sha256WithRSAEncryption", "id-sha256", "rsaEncryption"),
+    ("sha384WithRSAEncryption", "id-sha384", "rsaEncryption"),
+    ("sha512WithRSAEncryption", "id-sha512", "rsaEncryption"),
+    ("ecdsa-with-SHA224", "id-sha224", "id-ecPublicKey"),
+    ("ecdsa-with-SHA256", "id-sha256", "id-ecPublicKey"),
+    ("ecdsa-with-SHA384", "id-sha384", "id-ecPublicKey"),
+    ("ecdsa-with-SHA512", "id-sha512", "id-ecPublicKey"),
+    ("dsa-with-sha1", "id-sha1", "id-dsa"),
+    ("dsa-with-sha224", "id-sha224", "id-dsa"),
+    ("dsa-with-sha256", "id-sha256", "id-dsa"),
+    ("id-Ed25519", "id-sha512", "id-Ed25519"),
+    ("id-Ed448", "id-shake256", "id-Ed448"),
+];
+
+// Elliptic curves: (name, OID).
+//
+// NIST curves use their FIPS 186 names rather than the SECG ones.
+const CURVES: &[(&str, &str)] = &[
+    ("P-192", "1.2.840.10045.3.1.1"),
+    ("P-224", "1.3.132.0.33"),
+    ("P-256", "1.2.840.10045.3.1.7"),
+    ("P-384", "1.3.132.0.34"),
+    ("P-521", "1.3.132.0.35"),
+    ("secp256k1", "1.3.132.0.10"),
+    ("brainpoolP160r1", "1.3.36.3.3.2.8.1.1.1"),
+    ("brainpoolP192r1", "1.3.36.3.3.2.8.1.1.3"),
+    ("brainpoolP224r1", "1.3.36.3.3.2.8.1.1.5"),
+    ("brainpoolP256r1", "1.3.36.3.3.2.8.1.1.7"),
+    ("brainpoolP320r1", "1.3.36.3.3.2.8.1.1.9"),
+    ("brainpoolP384r1", "1.3.36.3.3.2.8.1.1.11"),
+    ("brainpoolP512r1", "1.3.36.3.3.2.8.1.1.13"),
+];
+
+// OpenSSL NIDs: (NID, OID), with the OpenSSL long name for reference.
+//
+// Curated from OpenSSL's `obj_mac.h`. NIDs are stable across OpenSSL releases.
+const NIDS: &[(i32, &str)] = &[
+    (4, "1.2.840.113549.2.5"),         // md5
+    (6, "1.2.840.113549.1.1.1"),       // rsaEncryption
+    (8, "1.2.840.113549.1.1.4"),       // md5WithRSAEncryption
+    (13, "2.5.4.3"),                   // commonName
+    (14, "2.5.4.6"),                   // countryName
+    (15, "2.5.4.7"),                   // localityName
+    (16, "2.5.4.8"),                   // stateOrProvinceName
+    (17, "2.5.4.10"),                  // organizationName
+    (18, "2.5.4.11"),                  // organizationalUnitName
+    (28, "1.2.840.113549.1.3.1"),      // dhKeyAgreement
+    (48, "1.2.840.113549.1.9.1"),      // emailAddress
+    (64, "1.3.14.3.2.26"),             // sha1
+    (65, "1.2.840.113549.1.1.5"),      // sha1WithRSAEncryption
+    (82, "2.5.29.14"),                 // subjectKeyIdentifier
+    (83, "2.5.29.15"),                 // keyUsage
+    (85, "2.5.29.17"),                 // subjectAltName
+    (87, "2.5.29.19"),                 // basicConstraints
+    (89, "2.5.29.32"),                 // certificatePolicies
+    (90, "2.5.29.35"),                 // authorityKeyIdentifier
+    (103, "2.5.29.31"),                // crlDistributionPoints
+    (116, "1.2.840.10040.4.1"),        // dsaEncryption
+    (126, "2.5.29.37"),                // extendedKeyUsage
+    (129, "1.3.6.1.5.5.7.3.1"),        // serverAuth
+    (130, "1.3.6.1.5.5.7.3.2"),        // clientAuth
+    (131, "1.3.6.1.5.5.7.3.3"),        // codeSigning
+    (132, "1.3.6.1.5.5.7.3.4"),        // emailProtection
+    (408, "1.2.840.10045.2.1"),        // id-ecPublicKey
+    (415, "1.2.840.10045.3.1.7"),      // prime256v1
+    (416, "1.2.840.10045.4.1"),        // ecdsa-with-SHA1
+    (419, "2.16.840.1.101.3.4.1.2"),   // aes-128-cbc
+    (423, "2.16.840.1.101.3.4.1.22"),  // aes-192-cbc
+    (427, "2.16.840.1.101.3.4.1.42"),  // aes-256-cbc
+    (668, "1.2.840.113549.1.1.11"),    // sha256WithRSAEncryption
+    (669, "1.2.840.113549.1.1.12"),    // sha384WithRSAEncryption
+    (670, "1.2.840.113549.1.1.13"),    // sha512WithRSAEncryption
+    (671, "1.2.840.113549.1.1.14"),    // sha224WithRSAEncryption
+    (672, "2.16.840.1.101.3.4.2.1"),   // sha256
+    (673, "2.16.840.1.101.3.4.2.2"),   // sha384
+    (674, "2.16.840.1.101.3.4.2.3"),   // sha512
+    (675, "2.16.840.1.101.3.4.2.4"),   // sha224
+    (714, "1.3.132.0.10"),             // secp256k1
+    (715, "1.3.132.0.34"),             // secp384r1
+    (716, "1.3.132.0.35"),             // secp521r1
+    (793, "1.2.840.10045.4.3.1"),      // ecdsa-with-SHA224
+    (794, "1.2.840.10045.4.3.2"),      // ecdsa-with-SHA256
+    (795, "1.2.840.10045.4.3.3"),      // ecdsa-with-SHA384
+    (796, "1.2.840.10045.4.3.4"),      // ecdsa-with-SHA512
+    (895, "2.16.840.1.101.3.4.1.6"),   // id-aes128-GCM
+    (901, "2.16.840.1.101.3.4.1.46"),  // id-aes256-GCM
+    (912, "1.2.840.113549.1.1.10"),    // RSASSA-PSS
+    (1034, "1.3.101.110"),             // X25519
+    (1035, "1.3.101.111"),             // X448
+    (1087, "1.3.101.112"),             // ED25519
+    (1088, "1.3.101.113"),             // ED448
+    (1096, "2.16.840.1.101.3.4.2.7"),  // sha3-224
+    (1097, "2.16.840.1.101.3.4.2.8"),  // sha3-256
+    (1098, "2.16.840.1.101.3.4.2.9"),  // sha3-384
+    (1099, "2.16.840.1.101.3.4.2.10"), // sha3-512
+];
+
 // Bases defined in other places.
 const BASES: &[(&str, &str)] = &[("id-ad-ocsp", "1.3.6.1.5.5.7.48.1")];
 const NO_BASES: &[(&str, &str)] = &[("", "")];
@@ -50,5 +155,5 @@ fn main() {
         }
     }
 
-    println!("{}", root.module());
+    println!("{}", root.module(SIGNATURES, CURVES, NIDS));
 }
@@ -0,0 +1,141 @@
+use crate::Asn1Parser;
+
+/// Parser for SMIv2 MIB modules.
+///
+/// Extracts OID assignments from `MODULE-IDENTITY`, `OBJECT-TYPE` and the
+/// other SMI macros, as well as plain `OBJECT IDENTIFIER` definitions.
+#[derive(Clone, Debug)]
+pub struct MibParser(Asn1Parser);
+
+impl MibParser {
+    // Descriptors must start with a lowercase letter (RFC 2578 section 3.1),
+    // which keeps `IMPORTS` lists from matching. The macro body is skipped up
+    // to the first `::=` outside of a quoted string.
+    const DEF: &'static str = r#"(?mx)
+        (?P<name>[a-z][a-zA-Z0-9-]*)                # name
+        \s+
+        (?:
+            MODULE-IDENTITY
+            | OBJECT-IDENTITY
+            | OBJECT-TYPE
+            | NOTIFICATION-TYPE
+            | OBJECT-GROUP
+            | NOTIFICATION-GROUP
+            | MODULE-COMPLIANCE
+            | AGENT-CAPABILITIES
+            | OBJECT\s+IDENTIFIER
+        )
+        (?:
+            "[^"]*"
+            |
+            [^":]
+            |
+            :[^:]
+        )*?
+        ::=
+        \s*
+        \{
+            \s*
+            (?P<base>[a-zA-Z][a-zA-Z0-9-]*\s*)??    # base
+            (?P<tail>                               # tail
+                (?:
+                    (?:
+                        [a-zA-Z][a-zA-Z0-9-]*\([0-9]+\)\s*
+                    )
+                    |
+                    (?:
+                        [0-9]+\s*
+                    )
+                )*
+            )
+        \}
+    "#;
+
+    // Well-known roots from `SNMPv2-SMI` (RFC 2578 section 2).
+    const ROOTS: &'static [(&'static str, &'static str)] = &[
+        ("org", "1.3"),
+        ("dod", "1.3.6"),
+        ("internet", "1.3.6.1"),
+        ("directory", "1.3.6.1.1"),
+        ("mgmt", "1.3.6.1.2"),
+        ("mib-2", "1.3.6.1.2.1"),
+        ("transmission", "1.3.6.1.2.1.10"),
+        ("experimental", "1.3.6.1.3"),
+        ("private", "1.3.6.1.4"),
+        ("enterprises", "1.3.6.1.4.1"),
+        ("security", "1.3.6.1.5"),
+        ("snmpV2", "1.3.6.1.6"),
+        ("snmpDomains", "1.3.6.1.6.1"),
+        ("snmpProxys", "1.3.6.1.6.2"),
+        ("snmpModules", "1.3.6.1.6.3"),
+    ];
+
+    pub fn new(mib: &str, bases: &[(&'static str, &'static str)]) -> Self {
+        let mut all = Self::ROOTS.to_vec();
+        all.extend_from_slice(bases);
+        Self(Asn1Parser::with_def(mib, Self::DEF, &all))
+    }
+
+    pub fn resolve(&self, name: &str) -> Option<String> {
+        self.0.resolve(name)
+    }
+
+    pub fn iter(&self) -> impl '_ + Iterator<Item = (String, String)> {
+        self.0.iter()
+    }
+}
+
+#[test]
+fn test() {
+    let mib = super::MibParser::new(
+        r#"
+            EXAMPLE-MIB DEFINITIONS ::= BEGIN
+
+            IMPORTS
+                MODULE-IDENTITY, OBJECT-TYPE, Integer32, enterprises
+                    FROM SNMPv2-SMI;
+
+            exampleMIB MODULE-IDENTITY
+                LAST-UPDATED "202401010000Z"
+                ORGANIZATION "Example, Inc."
+                CONTACT-INFO "postmaster@example.com"
+                DESCRIPTION  "A module ::= used for testing."
+                REVISION     "202401010000Z"
+                DESCRIPTION  "Initial revision."
+                ::= { enterprises 55555 }
+
+            exampleObjects OBJECT IDENTIFIER ::= { exampleMIB 1 }
+
+            exampleCounter OBJECT-TYPE
+                SYNTAX      Integer32 (0..100)
+                MAX-ACCESS  read-only
+                STATUS      current
+                DESCRIPTION "A counter."
+                ::= { exampleObjects 2 }
+
+            END
+        "#,
+        &[],
+    );
+
+    let mut iter = mib.iter();
+    assert_eq!(
+        Some((
+            "exampleCounter".to_string(),
+            "1.3.6.1.4.1.55555.1.2".to_string()
+        )),
+        iter.next()
+    );
+    assert_eq!(
+        Some(("exampleMIB".to_string(), "1.3.6.1.4.1.55555".to_string())),
+        iter.next()
+    );
+    assert_eq!(
+        Some((
+            "exampleObjects".to_string(),
+            "1.3.6.1.4.1.55555.1".to_string()
+        )),
+        iter.next()
+    );
+    assert_eq!(None, iter.next());
+}
@@ -62,6 +62,33 @@ impl Node {
         &self.symb
     }
 
+    /// Encode the OID as BER, which determines its position in the OID-sorted table.
+    pub fn ber(&self) -> Vec<u8> {
+        let arcs: Vec<u64> = self
+            .obid
+            .replace(' ', "")
+            .split('.')
+            .map(|arc| arc.parse().expect("invalid arc"))
+            .collect();
+
+        let mut ber = Vec::new();
+        let root = arcs[0] * 40 + arcs[1];
+
+        for mut arc in std::iter::once(root).chain(arcs[2..].iter().copied()) {
+            let mut groups = vec![(arc & 0x7F) as u8];
+            arc >>= 7;
+
+            while arc > 0 {
+                groups.push((arc & 0x7F) as u8 | 0x80);
+                arc >>= 7;
+            }
+
+            ber.extend(groups.iter().rev());
+        }
+
+        ber
+    }
+
     pub fn definition(&self) -> TokenStream {
         let obid = self.obid.replace(' ', ""); // Fix a typo.
         let symb = &self.symb;
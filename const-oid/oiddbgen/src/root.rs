@@ -1,8 +1,8 @@
 use crate::{node::Node, spec::Spec};
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
 use quote::quote;
 
 #[derive(Clone, Debug, Default)]
@@ -21,13 +21,70 @@ impl Root {
             .insert(Node::new(obid, name));
     }
 
-    pub fn module(&self) -> TokenStream {
+    /// Find the path to the constant for the given name, e.g. `rfc5912::ID_SHA_256`.
+    fn path_of(&self, name: &str) -> Option<TokenStream> {
+        self.0
+            .iter()
+            .find_map(|(spec, s)| s.symbol_of(name).map(|symb| quote! { #spec::#symb }))
+    }
+
+    /// Generate the database module.
+    ///
+    /// The `signatures` are `(signature, digest, public key)` name triples for
+    /// composite signature algorithms, the `curves` are `(name, OID)` pairs
+    /// for elliptic curves, and the `nids` are `(NID, OID)` pairs for OpenSSL
+    /// numeric identifiers.
+    ///
+    /// Besides the records listed by spec, the database includes a table of
+    /// the first name of each OID sorted by OID, for lookups by OID.
+    pub fn module(
+        &self,
+        signatures: &[(&str, &str, &str)],
+        curves: &[(&str, &str)],
+        nids: &[(i32, &str)],
+    ) -> TokenStream {
         let mut mods = TokenStream::default();
         let mut recs = TokenStream::default();
+        let mut sigs = TokenStream::default();
+        let mut ecs = TokenStream::default();
+        let mut nid_recs = TokenStream::default();
+        let mut by_oid = Vec::new();
+        let mut seen = BTreeSet::new();
 
         for (spec, s) in &self.0 {
             mods.extend(s.module(spec));
             recs.extend(s.records(quote! { &#spec }));
+
+            // `Database::by_oid` returns the first name listed for an OID, so the first wins.
+            for (ber, rec) in s.reverse_records(quote! { &#spec }) {
+                if seen.insert(ber.clone()) {
+                    by_oid.push((ber, rec));
+                }
+            }
+        }
+
+        // Sorted by the length and then the bytes of the BER encoding, as the binary search in
+        // `Database::by_oid` expects.
+        by_oid.sort_by(|(a, _), (b, _)| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+        let by_oid: TokenStream = by_oid.into_iter().map(|(_, rec)| rec).collect();
+
+        for (sig, digest, pk) in signatures {
+            let path = |name: &str| {
+                self.path_of(name)
+                    .unwrap_or_else(|| panic!("unknown OID name: {name}"))
+            };
+
+            let (sig, digest, pk) = (path(sig), path(digest), path(pk));
+            sigs.extend(quote! { (&#sig, &#digest, &#pk), });
+        }
+
+        for (name, obid) in curves {
+            ecs.extend(quote! { (&crate::ObjectIdentifier::new_unwrap(#obid), #name), });
+        }
+
+        for (nid, obid) in nids {
+            let nid = Literal::i32_unsuffixed(*nid);
+            nid_recs.extend(quote! { (#nid, crate::ObjectIdentifier::new_unwrap(#obid)), });
         }
 
         quote! {
@@ -37,7 +94,18 @@ impl Root {
 
             pub const DB: super::Database<'static> = super::Database(&[
                 #recs
+            ], &[
+                #sigs
+            ], &[
+                #ecs
+            ], &[
+                #by_oid
             ]);
+
+            #[cfg(feature = "openssl-nid")]
+            pub(super) const NIDS: &[(i32, crate::ObjectIdentifier)] = &[
+                #nid_recs
+            ];
         }
     }
 }
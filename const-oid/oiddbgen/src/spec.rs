@@ -13,6 +13,11 @@ impl Spec {
         self.0.insert(value)
     }
 
+    /// Find the symbol of the node with the given name.
+    pub fn symbol_of(&self, name: &str) -> Option<&Ident> {
+        self.0.iter().find(|n| n.name() == name).map(|n| n.symbol())
+    }
+
     pub fn records(&self, path: TokenStream) -> TokenStream {
         let mut stream = TokenStream::default();
 
@@ -25,6 +30,18 @@ impl Spec {
         stream
     }
 
+    /// Records along with the BER encoding of their OIDs, for sorting by OID.
+    pub fn reverse_records(&self, path: TokenStream) -> Vec<(Vec<u8>, TokenStream)> {
+        self.0
+            .iter()
+            .map(|n| {
+                let name = n.name();
+                let symb = n.symbol();
+                (n.ber(), quote! { (#path::#symb, #name), })
+            })
+            .collect()
+    }
+
     pub fn module(&self, spec: &Ident) -> TokenStream {
         let mut defs = TokenStream::default();
 
@@ -1,7 +1,7 @@
 //! Arcs are integer values which exist within an OID's hierarchy.
 
 use crate::{Error, Result};
-use core::mem;
+use core::fmt;
 
 #[cfg(doc)]
 use crate::ObjectIdentifier;
@@ -21,18 +21,16 @@ pub type Arc = u32;
 /// Maximum value of the first arc in an OID.
 pub(crate) const ARC_MAX_FIRST: Arc = 2;
 
-/// Maximum value of the second arc in an OID.
+/// Maximum value of the second arc in an OID when the first arc is 0 or 1.
 pub(crate) const ARC_MAX_SECOND: Arc = 39;
 
-/// Maximum number of bytes supported in an arc.
-const ARC_MAX_BYTES: usize = mem::size_of::<Arc>();
-
-/// Maximum value of the last byte in an arc.
-const ARC_MAX_LAST_OCTET: u8 = 0b11110000; // Max bytes of leading 1-bits
-
 /// [`Iterator`] over [`Arc`] values (a.k.a. nodes) in an [`ObjectIdentifier`].
 ///
 /// This iterates over all arcs in an OID, including the root.
+///
+/// `Arcs` is a cursor over borrowed bytes, so it's cheap to [`Clone`] in order
+/// to look ahead: the clone and the original advance independently.
+#[derive(Clone, Debug)]
 pub struct Arcs<'a> {
     /// OID bytes we're iterating over.
     bytes: &'a [u8],
@@ -54,53 +52,63 @@ impl<'a> Arcs<'a> {
     ///
     /// This method is fallible so it can be used as a first pass to determine
     /// that the arcs in the OID are well-formed.
+    #[inline]
     pub(crate) fn try_next(&mut self) -> Result<Option<Arc>> {
         match self.cursor {
             // Indicates we're on the root arc
             None => {
-                let root_byte = *self.bytes.first().ok_or(Error::Empty)?;
-                let root = RootArcs::try_from(root_byte)?;
+                let (root, _) = self.decode_base128(0)?.ok_or(Error::Empty)?;
                 self.cursor = Some(0);
-                Ok(Some(root.first_arc()))
+                Ok(Some(RootArcs::from_value(root).first_arc()))
             }
             Some(0) => {
-                let root = RootArcs::try_from(self.bytes[0])?;
-                self.cursor = Some(1);
-                Ok(Some(root.second_arc()))
+                let (root, next) = self.decode_base128(0)?.ok_or(Error::Empty)?;
+                self.cursor = Some(next);
+                Ok(Some(RootArcs::from_value(root).second_arc()))
             }
-            Some(offset) => {
-                let mut result = 0;
-                let mut arc_bytes = 0;
-
-                loop {
-                    let len = checked_add!(offset, arc_bytes);
-
-                    match self.bytes.get(len).cloned() {
-                        // The arithmetic below includes advance checks
-                        // against `ARC_MAX_BYTES` and `ARC_MAX_LAST_OCTET`
-                        // which ensure the operations will not overflow.
-                        #[allow(clippy::arithmetic_side_effects)]
-                        Some(byte) => {
-                            arc_bytes = checked_add!(arc_bytes, 1);
-
-                            if (arc_bytes > ARC_MAX_BYTES) && (byte & ARC_MAX_LAST_OCTET != 0) {
-                                return Err(Error::ArcTooBig);
-                            }
-
-                            result = result << 7 | (byte & 0b1111111) as Arc;
-
-                            if byte & 0b10000000 == 0 {
-                                self.cursor = Some(checked_add!(offset, arc_bytes));
-                                return Ok(Some(result));
-                            }
-                        }
-                        None => {
-                            if arc_bytes == 0 {
-                                return Ok(None);
-                            } else {
-                                return Err(Error::Base128);
-                            }
-                        }
+            Some(offset) => match self.decode_base128(offset)? {
+                Some((arc, next)) => {
+                    self.cursor = Some(next);
+                    Ok(Some(arc))
+                }
+                None => Ok(None),
+            },
+        }
+    }
+
+    /// Decode the base 128 value starting at the given offset, returning it along with the
+    /// offset of the next value, or `None` if there are no bytes remaining.
+    #[inline]
+    fn decode_base128(&self, offset: usize) -> Result<Option<(Arc, usize)>> {
+        let mut result = 0;
+        let mut arc_bytes = 0;
+
+        loop {
+            let len = checked_add!(offset, arc_bytes);
+
+            match self.bytes.get(len).cloned() {
+                // The arithmetic below includes an advance check
+                // against `Arc::MAX` which ensures the shift will
+                // not overflow.
+                #[allow(clippy::arithmetic_side_effects)]
+                Some(byte) => {
+                    arc_bytes = checked_add!(arc_bytes, 1);
+
+                    if result > Arc::MAX >> 7 {
+                        return Err(Error::ArcTooBig);
+                    }
+
+                    result = result << 7 | (byte & 0b1111111) as Arc;
+
+                    if byte & 0b10000000 == 0 {
+                        return Ok(Some((result, checked_add!(offset, arc_bytes))));
+                    }
+                }
+                None => {
+                    if arc_bytes == 0 {
+                        return Ok(None);
+                    } else {
+                        return Err(Error::Base128 { offset });
                     }
                 }
             }
@@ -108,21 +116,149 @@ impl<'a> Arcs<'a> {
     }
 }
 
+/// Validate the BER serialization of an OID in a single pass, without
+/// decoding the values of its arcs.
+///
+/// Accepts exactly the same inputs as iterating over [`Arcs`] to completion
+/// with [`Arcs::try_next`]. Returns `true` if every arc is minimally encoded
+/// (i.e. the encoding is also valid DER), or `false` if any arc contains
+/// leading `0x80` bytes.
+pub(crate) fn validate(bytes: &[u8]) -> Result<bool> {
+    validate_bounded(bytes, usize::MAX)
+}
+
+/// Like [`validate`], but fail with [`Error::TooManyArcs`] as soon as more
+/// than `max_arcs` arcs have been seen.
+pub(crate) fn validate_bounded(bytes: &[u8], max_arcs: usize) -> Result<bool> {
+    if bytes.is_empty() {
+        return Err(Error::Empty);
+    }
+
+    // The first group (encoding the first two arcs) is validated like any other
+    let mut body = bytes;
+    let mut minimal = true;
+    let mut arcs = 1usize;
+
+    while !body.is_empty() {
+        arcs = arcs.saturating_add(1);
+
+        if arcs > max_arcs {
+            return Err(Error::TooManyArcs);
+        }
+
+        // Each arc ends with the first byte without the continuation bit set
+        let (group, remaining) = match body.iter().position(|&byte| byte & 0b10000000 == 0) {
+            Some(pos) => body.split_at(pos.saturating_add(1)),
+            None => {
+                // Truncated arc: oversized arcs are reported before truncation
+                check_arc_bytes(body)?;
+                return Err(Error::Base128 {
+                    offset: bytes.len().saturating_sub(body.len()),
+                });
+            }
+        };
+
+        minimal &= check_arc_bytes(group)?;
+        body = remaining;
+    }
+
+    Ok(minimal)
+}
+
+/// Check that the base 128 bytes of a single arc fit in an [`Arc`], returning
+/// whether the arc is minimally encoded.
+fn check_arc_bytes(bytes: &[u8]) -> Result<bool> {
+    // Leading `0x80` bytes don't contribute to the value of the arc
+    let leading = bytes
+        .iter()
+        .position(|&byte| byte != 0b10000000)
+        .unwrap_or(bytes.len());
+
+    match &bytes[leading..] {
+        // 5 base 128 bytes can hold 35-bits, i.e. the first may use 4-bits
+        [first, _, _, _, _, ..] if first & 0b1111111 > 0b1111 => Err(Error::ArcTooBig),
+        [_, _, _, _, _, _, ..] => Err(Error::ArcTooBig),
+        _ => Ok(leading == 0),
+    }
+}
+
 impl<'a> Iterator for Arcs<'a> {
     type Item = Arc;
 
+    #[inline]
     fn next(&mut self) -> Option<Arc> {
         // ObjectIdentifier constructors should ensure the OID is well-formed
         self.try_next().expect("OID malformed")
     }
+
+    /// Bounds the number of remaining arcs by the number of remaining bytes, since every arc is
+    /// encoded as at least one byte, except that the first byte group encodes two arcs.
+    ///
+    /// The exact count requires a scan for the final byte of each arc.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.bytes.len().saturating_sub(self.cursor.unwrap_or(0));
+
+        // The second arc is yet to be produced from the first byte group
+        let root = usize::from(self.cursor.is_none() && remaining > 0);
+
+        (
+            remaining.min(1).saturating_add(root),
+            Some(remaining.saturating_add(root)),
+        )
+    }
+}
+
+/// [`Iterator`] over the base 128 byte groups which encode each [`Arc`] of an
+/// [`ObjectIdentifier`].
+///
+/// The first group encodes both the first and second arcs.
+#[derive(Clone, Debug)]
+pub struct ByteGroups<'a> {
+    /// Remaining OID bytes.
+    bytes: &'a [u8],
+}
+
+impl<'a> ByteGroups<'a> {
+    /// Create a new iterator over the byte groups of an OID encoded as BER bytes.
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+}
+
+impl<'a> Iterator for ByteGroups<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        if self.bytes.is_empty() {
+            return None;
+        }
+
+        // Groups end with the first byte which doesn't have the continuation bit set
+        let len = self
+            .bytes
+            .iter()
+            .position(|byte| byte & 0b10000000 == 0)
+            .map_or(self.bytes.len(), |pos| pos.saturating_add(1));
+
+        let (group, remaining) = self.bytes.split_at(len);
+        self.bytes = remaining;
+        Some(group)
+    }
 }
 
-/// Byte containing the first and second arcs of an OID.
+/// First and second arcs of an OID.
 ///
-/// This is represented this way in order to reduce the overall size of the
-/// [`ObjectIdentifier`] struct.
+/// These are BER-encoded together as a single base 128 value, `40 * first + second`. The second
+/// arc is limited to the range 0-39 when the first arc is 0 or 1, but may take any value when the
+/// first arc is 2 (e.g. `2.999`), in which case the encoded value may span several bytes.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
-struct RootArcs(u8);
+pub(crate) struct RootArcs {
+    /// First arc.
+    first: Arc,
+
+    /// Second arc.
+    second: Arc,
+}
 
 impl RootArcs {
     /// Create [`RootArcs`] from the first and second arc values represented
@@ -132,46 +268,191 @@ impl RootArcs {
             return Err(Error::ArcInvalid { arc: first_arc });
         }
 
-        if second_arc > ARC_MAX_SECOND {
+        if first_arc < ARC_MAX_FIRST && second_arc > ARC_MAX_SECOND {
             return Err(Error::ArcInvalid { arc: second_arc });
         }
 
-        // The checks above ensure this operation will not overflow
-        #[allow(clippy::arithmetic_side_effects)]
-        let byte = (first_arc * (ARC_MAX_SECOND + 1)) as u8 + second_arc as u8;
+        // The combined value must itself fit in an `Arc`
+        if second_arc > Arc::MAX - ARC_MAX_FIRST * (ARC_MAX_SECOND + 1) {
+            return Err(Error::ArcTooBig);
+        }
 
-        Ok(Self(byte))
+        Ok(Self {
+            first: first_arc,
+            second: second_arc,
+        })
     }
 
-    /// Get the value of the first arc
+    /// Decode [`RootArcs`] from the value of the first base 128 encoded arc.
+    ///
+    /// Every value is valid: values of 80 and up have a first arc of 2.
+    // Divisor is nonzero and the subtraction is checked by the branch
+    #[allow(clippy::arithmetic_side_effects)]
+    pub(crate) const fn from_value(value: Arc) -> Self {
+        let first = value / (ARC_MAX_SECOND + 1);
+
+        if first < ARC_MAX_FIRST {
+            Self {
+                first,
+                second: value % (ARC_MAX_SECOND + 1),
+            }
+        } else {
+            Self {
+                first: ARC_MAX_FIRST,
+                second: value - ARC_MAX_FIRST * (ARC_MAX_SECOND + 1),
+            }
+        }
+    }
+
+    /// Get the value of the first base 128 encoded arc.
+    // Ensured not to overflow by the checks in the constructor
     #[allow(clippy::arithmetic_side_effects)]
+    pub(crate) const fn value(self) -> Arc {
+        self.first * (ARC_MAX_SECOND + 1) + self.second
+    }
+
+    /// Get the value of the first arc
     pub(crate) const fn first_arc(self) -> Arc {
-        self.0 as Arc / (ARC_MAX_SECOND + 1)
+        self.first
     }
 
     /// Get the value of the second arc
-    #[allow(clippy::arithmetic_side_effects)]
     pub(crate) const fn second_arc(self) -> Arc {
-        self.0 as Arc % (ARC_MAX_SECOND + 1)
+        self.second
     }
 }
 
-impl TryFrom<u8> for RootArcs {
-    type Error = Error;
+/// Registration authority identified by the first arc of an OID.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum RootAuthority {
+    /// ITU-T (root arc `0`).
+    ItuT,
 
-    // Ensured not to overflow by constructor invariants
-    #[allow(clippy::arithmetic_side_effects)]
-    fn try_from(octet: u8) -> Result<Self> {
-        let first = octet as Arc / (ARC_MAX_SECOND + 1);
-        let second = octet as Arc % (ARC_MAX_SECOND + 1);
-        let result = Self::new(first, second)?;
-        debug_assert_eq!(octet, result.0);
-        Ok(result)
+    /// ISO (root arc `1`).
+    Iso,
+
+    /// Joint ISO/ITU-T (root arc `2`).
+    JointIsoItuT,
+}
+
+impl RootAuthority {
+    /// Get the [`RootAuthority`] for the given first arc, if it is a valid root arc.
+    pub const fn from_arc(arc: Arc) -> Option<Self> {
+        match arc {
+            0 => Some(Self::ItuT),
+            1 => Some(Self::Iso),
+            2 => Some(Self::JointIsoItuT),
+            _ => None,
+        }
+    }
+
+    /// Get the [`RootAuthority`] with the given ASN.1 name (case-sensitive), if any.
+    ///
+    /// In addition to the names returned by [`RootAuthority::as_str`], this accepts the legacy
+    /// `ccitt` and `joint-iso-ccitt` names.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "itu-t" | "ccitt" => Some(Self::ItuT),
+            "iso" => Some(Self::Iso),
+            "joint-iso-itu-t" | "joint-iso-ccitt" => Some(Self::JointIsoItuT),
+            _ => None,
+        }
+    }
+
+    /// Get the root arc value for this authority.
+    pub const fn arc(self) -> Arc {
+        match self {
+            Self::ItuT => 0,
+            Self::Iso => 1,
+            Self::JointIsoItuT => 2,
+        }
+    }
+
+    /// Get the ASN.1 name of this authority, e.g. `joint-iso-itu-t`.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::ItuT => "itu-t",
+            Self::Iso => "iso",
+            Self::JointIsoItuT => "joint-iso-itu-t",
+        }
+    }
+
+    /// Get the Unicode label of this authority used in the OID-IRI form, e.g. `Joint-ISO-ITU-T`.
+    pub const fn iri_label(self) -> &'static str {
+        match self {
+            Self::ItuT => "ITU-T",
+            Self::Iso => "ISO",
+            Self::JointIsoItuT => "Joint-ISO-ITU-T",
+        }
+    }
+}
+
+impl fmt::Display for RootAuthority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
     }
 }
 
-impl From<RootArcs> for u8 {
-    fn from(root_arcs: RootArcs) -> u8 {
-        root_arcs.0
+#[cfg(test)]
+mod tests {
+    use super::{validate, Arcs};
+    use crate::Result;
+
+    /// Validate by fully decoding the arcs.
+    fn validate_by_decoding(bytes: &[u8]) -> Result<()> {
+        let mut arcs = Arcs::new(bytes);
+        while arcs.try_next()?.is_some() {}
+        Ok(())
+    }
+
+    fn assert_parity(bytes: &[u8]) {
+        assert_eq!(
+            validate(bytes).map(|_| ()),
+            validate_by_decoding(bytes),
+            "{:02X?}",
+            bytes
+        );
+    }
+
+    #[test]
+    fn validate_parity_exhaustive() {
+        assert_parity(&[]);
+
+        for a in 0..=u8::MAX {
+            assert_parity(&[a]);
+
+            for b in 0..=u8::MAX {
+                assert_parity(&[a, b]);
+
+                for c in 0..=u8::MAX {
+                    assert_parity(&[a, b, c]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn validate_parity_long_arcs() {
+        const BYTES: [u8; 10] = [0x00, 0x01, 0x0F, 0x10, 0x7F, 0x80, 0x81, 0x8F, 0x90, 0xFF];
+
+        let mut buf = [0x2Au8; 7];
+
+        for n in 0..10usize.pow(6) {
+            let mut i = n;
+
+            for byte in &mut buf[1..] {
+                *byte = BYTES[i % 10];
+                i /= 10;
+            }
+
+            assert_parity(&buf);
+        }
+    }
+
+    #[test]
+    fn validate_minimal() {
+        assert_eq!(validate(&[0x2A, 0x86, 0x48]), Ok(true));
+        assert_eq!(validate(&[0x2A, 0x80, 0x86, 0x48]), Ok(false));
+        assert_eq!(validate(&[0x2A, 0x80, 0x00]), Ok(false));
     }
 }
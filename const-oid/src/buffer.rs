@@ -1,5 +1,10 @@
 //! Array-backed buffer for BER bytes.
 
+use crate::{Error, Result};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
 /// Array-backed buffer for storing BER computed at compile-time.
 #[derive(Copy, Clone, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub struct Buffer<const SIZE: usize> {
@@ -11,7 +16,51 @@ pub struct Buffer<const SIZE: usize> {
 }
 
 impl<const SIZE: usize> Buffer<SIZE> {
+    /// Create a buffer which is completely filled by the given array.
+    ///
+    /// # Panics
+    ///
+    /// If `SIZE` exceeds 255 bytes, the maximum length a buffer can hold.
+    pub const fn from_array(bytes: [u8; SIZE]) -> Self {
+        if SIZE > u8::MAX as usize {
+            Error::Length.panic();
+        }
+
+        Self {
+            length: SIZE as u8,
+            bytes,
+        }
+    }
+
+    /// Create a buffer containing a copy of the given bytes.
+    ///
+    /// Returns [`Error::Length`] if `bytes` is longer than `SIZE` or 255 bytes.
+    pub const fn new_const(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() > SIZE || bytes.len() > u8::MAX as usize {
+            return Err(Error::Length);
+        }
+
+        let mut buffer = [0u8; SIZE];
+        let mut i = 0usize;
+
+        while i < bytes.len() {
+            buffer[i] = bytes[i];
+
+            // Won't overflow due to `i < bytes.len()` check above
+            #[allow(clippy::arithmetic_side_effects)]
+            {
+                i += 1;
+            }
+        }
+
+        Ok(Self {
+            length: bytes.len() as u8,
+            bytes: buffer,
+        })
+    }
+
     /// Borrow the inner byte slice.
+    #[inline]
     pub const fn as_bytes(&self) -> &[u8] {
         self.bytes.split_at(self.length as usize).0
     }
@@ -46,7 +95,76 @@ impl<const SIZE: usize> Buffer<SIZE> {
 }
 
 impl<const SIZE: usize> AsRef<[u8]> for Buffer<SIZE> {
+    #[inline]
     fn as_ref(&self) -> &[u8] {
         self.as_bytes()
     }
 }
+
+/// Zeroes the entire backing array (including any unused trailing bytes) as
+/// well as the length.
+///
+/// A zero length is not a valid OID serialization, so an
+/// [`ObjectIdentifier`][`crate::ObjectIdentifier`] containing the buffer must
+/// not be used afterwards other than to be dropped or overwritten.
+///
+/// `Buffer` is `Copy`, so it can't impl `ZeroizeOnDrop`. Wrap it in
+/// [`zeroize::Zeroizing`] to opt into zeroization on drop.
+#[cfg(feature = "zeroize")]
+impl<const SIZE: usize> Zeroize for Buffer<SIZE> {
+    fn zeroize(&mut self) {
+        self.length.zeroize();
+        self.bytes.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Buffer;
+    use crate::Error;
+
+    const FROM_ARRAY: Buffer<3> = Buffer::from_array([0x2A, 0x03, 0x04]);
+
+    const NEW_CONST: Buffer<8> = match Buffer::new_const(&[0x2A, 0x03, 0x04]) {
+        Ok(buffer) => buffer,
+        Err(err) => err.panic(),
+    };
+
+    #[test]
+    fn const_construction() {
+        assert_eq!(FROM_ARRAY.as_bytes(), &[0x2A, 0x03, 0x04]);
+        assert_eq!(FROM_ARRAY.len(), 3);
+        assert_eq!(NEW_CONST.as_bytes(), &[0x2A, 0x03, 0x04]);
+        assert_eq!(NEW_CONST.len(), 3);
+        assert_eq!(NEW_CONST.bytes[3..], [0; 5]);
+    }
+
+    #[test]
+    fn new_const_length() {
+        assert_eq!(Buffer::<3>::new_const(&[]).map(|b| b.len()), Ok(0));
+        assert_eq!(Buffer::<3>::new_const(&[1, 2, 3]).map(|b| b.len()), Ok(3));
+        assert_eq!(
+            Buffer::<3>::new_const(&[1, 2, 3, 4]).err(),
+            Some(Error::Length)
+        );
+        assert_eq!(
+            Buffer::<512>::new_const(&[0; 256]).err(),
+            Some(Error::Length)
+        );
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn zeroize() {
+        use zeroize::Zeroize;
+
+        let mut buffer = Buffer {
+            length: 3,
+            bytes: [0xFF; 8],
+        };
+
+        buffer.zeroize();
+        assert_eq!(buffer.length, 0);
+        assert_eq!(buffer.bytes, [0; 8]);
+    }
+}
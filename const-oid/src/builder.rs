@@ -0,0 +1,63 @@
+//! Builder which accumulates arcs and validates them when finished.
+
+use crate::{Arc, Error, ObjectIdentifier, Result};
+
+/// Maximum number of arcs in an OID of the default size: every arc after the first two occupies
+/// at least one byte, and the first two share a byte.
+const MAX_ARCS: usize = ObjectIdentifier::MAX_SIZE + 1;
+
+/// Builder for an [`ObjectIdentifier`] which buffers arcs, deferring all validation until
+/// [`OidBuilder::finish`] is called.
+///
+/// This is convenient when an OID is assembled from optional pieces, as arcs can be pushed
+/// conditionally without handling an error at each step.
+#[derive(Clone, Debug)]
+pub struct OidBuilder {
+    /// Arcs pushed so far.
+    arcs: [Arc; MAX_ARCS],
+
+    /// Number of arcs pushed, which may exceed the capacity of `arcs`.
+    len: usize,
+}
+
+impl OidBuilder {
+    /// Create a builder with no arcs.
+    pub const fn new() -> Self {
+        Self {
+            arcs: [0; MAX_ARCS],
+            len: 0,
+        }
+    }
+
+    /// Append an arc.
+    ///
+    /// Arcs beyond the number which could possibly fit in an [`ObjectIdentifier`] are discarded,
+    /// and cause [`OidBuilder::finish`] to fail with [`Error::Length`].
+    pub fn push(&mut self, arc: Arc) {
+        if let Some(slot) = self.arcs.get_mut(self.len) {
+            *slot = arc;
+        }
+
+        self.len = self.len.saturating_add(1);
+    }
+
+    /// Validate and encode the arcs pushed so far, as with [`ObjectIdentifier::from_arcs`].
+    pub fn finish(self) -> Result<ObjectIdentifier> {
+        let arcs = self.arcs.get(..self.len).ok_or(Error::Length)?;
+        ObjectIdentifier::from_arcs(arcs.iter().copied())
+    }
+}
+
+impl Default for OidBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Extend<Arc> for OidBuilder {
+    fn extend<T: IntoIterator<Item = Arc>>(&mut self, iter: T) {
+        for arc in iter {
+            self.push(arc);
+        }
+    }
+}
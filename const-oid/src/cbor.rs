@@ -0,0 +1,89 @@
+//! CBOR encoding of OIDs as tagged byte strings, as described in [RFC 9090].
+//!
+//! [RFC 9090]: https://www.rfc-editor.org/rfc/rfc9090
+
+use crate::{Error, ObjectIdentifier, Result};
+
+/// CBOR tag for an absolute OID.
+const OID_TAG: u8 = 111;
+
+/// Major type of a byte string, in the high 3 bits of the initial byte of its head.
+const BYTE_STRING: u8 = 2 << 5;
+
+/// Major type of a tag, in the high 3 bits of the initial byte of its head.
+const TAG: u8 = 6 << 5;
+
+/// Additional information indicating the argument is in the following byte.
+const ONE_BYTE: u8 = 24;
+
+/// Head of tag 111, in its preferred (shortest) encoding.
+const OID_TAG_HEAD: [u8; 2] = [TAG | ONE_BYTE, OID_TAG];
+
+impl ObjectIdentifier {
+    /// Parse an OID from a CBOR data item consisting of tag 111 enclosing a byte string, which
+    /// contains the BER/DER encoding of the OID as accepted by [`ObjectIdentifier::from_bytes`].
+    ///
+    /// Only the preferred (shortest) encodings of the tag and length are accepted, as required of
+    /// deterministically encoded CBOR. Returns [`Error::CborInvalid`] for any other data item,
+    /// including OIDs without the tag, and [`Error::TrailingData`] if any bytes follow the item.
+    pub fn from_cbor_bytes(cbor: &[u8]) -> Result<Self> {
+        if cbor.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        let bytes = cbor.strip_prefix(&OID_TAG_HEAD).ok_or(Error::CborInvalid)?;
+
+        let (&initial, bytes) = bytes.split_first().ok_or(Error::Length)?;
+
+        if initial & !0x1F != BYTE_STRING {
+            return Err(Error::CborInvalid);
+        }
+
+        let (len, bytes) = match initial & 0x1F {
+            len @ 0..=23 => (len as usize, bytes),
+            ONE_BYTE => match bytes.split_first() {
+                Some((&len, bytes)) if len >= ONE_BYTE => (len as usize, bytes),
+                Some(_) => return Err(Error::CborInvalid),
+                None => return Err(Error::Length),
+            },
+            // Preferred encodings of 2, 4, or 8-byte lengths exceed the maximum size of an OID
+            25..=27 => return Err(Error::Length),
+            // Reserved values and indefinite-length byte strings
+            _ => return Err(Error::CborInvalid),
+        };
+
+        if bytes.len() < len {
+            return Err(Error::Length);
+        }
+
+        let (value, remaining) = bytes.split_at(len);
+
+        if !remaining.is_empty() {
+            return Err(Error::TrailingData);
+        }
+
+        Self::from_bytes(value)
+    }
+
+    /// Encode this OID as a CBOR data item consisting of tag 111 enclosing a byte string
+    /// containing its BER/DER encoding, i.e. the inverse of [`ObjectIdentifier::from_cbor_bytes`].
+    #[cfg(feature = "alloc")]
+    pub fn to_cbor_bytes(&self) -> alloc::vec::Vec<u8> {
+        let value = self.as_bytes();
+
+        // Can't truncate as the length of `Buffer` is a `u8`
+        let len = value.len() as u8;
+
+        let mut out = alloc::vec::Vec::with_capacity(value.len().saturating_add(4));
+        out.extend_from_slice(&OID_TAG_HEAD);
+
+        if len < ONE_BYTE {
+            out.push(BYTE_STRING | len);
+        } else {
+            out.extend_from_slice(&[BYTE_STRING | ONE_BYTE, len]);
+        }
+
+        out.extend_from_slice(value);
+        out
+    }
+}
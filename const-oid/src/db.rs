@@ -2,6 +2,33 @@
 //!
 //! The contents of this database are generated from the official IANA
 //! [Object Identifier Descriptors] Registry CSV file and from [RFC 5280].
+//! With the `openssl-nid` feature, a curated table of OpenSSL NIDs is also
+//! available via `nid_to_oid` and `oid_to_nid`.
+//!
+//! Each OID is also available as a typed constant in a module named after the
+//! specification which defines it, e.g. [`rfc5912::RSA_ENCRYPTION`]. These are
+//! ordinary `const` items, so they can be used as patterns:
+//!
+//! ```
+//! use const_oid::{db::rfc5912, ObjectIdentifier};
+//!
+//! fn describe(oid: ObjectIdentifier) -> &'static str {
+//!     match oid {
+//!         rfc5912::RSA_ENCRYPTION => "RSA",
+//!         rfc5912::ID_EC_PUBLIC_KEY => "EC",
+//!         _ => "unknown",
+//!     }
+//! }
+//!
+//! assert_eq!(describe(rfc5912::RSA_ENCRYPTION), "RSA");
+//! ```
+//!
+//! Constant names are derived from the name of the OID by upper-casing the
+//! first letter of each hyphen-separated word and converting the result to
+//! `UPPER_SNAKE_CASE`, e.g. `id-ecPublicKey` becomes `ID_EC_PUBLIC_KEY` and
+//! `sha256WithRSAEncryption` becomes `SHA_256_WITH_RSA_ENCRYPTION`. Names are
+//! stable as long as the upstream name of the OID is.
+//!
 //! If we are missing values you care about, please contribute a patch to
 //! `oiddbgen` (a subcrate in the source code) to generate the values from
 //! the relevant standard.
@@ -15,7 +42,8 @@ mod gen;
 
 pub use gen::*;
 
-use crate::{Error, ObjectIdentifier};
+use crate::{Arcs, Error, ObjectIdentifier};
+use core::cmp::Ordering;
 
 /// A const implementation of case-insensitive ASCII equals.
 const fn eq_case(lhs: &[u8], rhs: &[u8]) -> bool {
@@ -35,11 +63,142 @@ const fn eq_case(lhs: &[u8], rhs: &[u8]) -> bool {
     true
 }
 
+/// Order BER-encoded OIDs by length and then bytewise, as the OID-sorted table of a [`Database`]
+/// is ordered.
+const fn cmp_ber(lhs: &[u8], rhs: &[u8]) -> Ordering {
+    if lhs.len() != rhs.len() {
+        return if lhs.len() < rhs.len() {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        };
+    }
+
+    let mut i = 0usize;
+    while i < lhs.len() {
+        if lhs[i] != rhs[i] {
+            return if lhs[i] < rhs[i] {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        }
+
+        i += 1;
+    }
+
+    Ordering::Equal
+}
+
+/// Placeholder for the unfilled slots of the table built by [`sort_records`].
+const PLACEHOLDER: ObjectIdentifier = ObjectIdentifier::new_unwrap("0.0.0");
+
+/// Count the distinct OIDs in `records`, i.e. the length of the table built by [`sort_records`].
+///
+/// Used by the [`oid_db!`][`crate::oid_db`] macro.
+#[doc(hidden)]
+pub const fn count_oids(records: &[(&ObjectIdentifier, &str)]) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+
+    while i < records.len() {
+        let mut j = 0;
+
+        while j < i && !records[j].0.ber.eq(&records[i].0.ber) {
+            j += 1;
+        }
+
+        if j == i {
+            count += 1;
+        }
+
+        i += 1;
+    }
+
+    count
+}
+
+/// Build the OID-sorted table of a [`Database`] from its records, keeping the first name listed
+/// for each OID. `N` must be the number of distinct OIDs as computed by [`count_oids`].
+///
+/// Used by the [`oid_db!`][`crate::oid_db`] macro.
+#[doc(hidden)]
+pub const fn sort_records<'a, const N: usize>(
+    records: &[(&'a ObjectIdentifier, &'a str)],
+) -> [(&'a ObjectIdentifier, &'a str); N] {
+    let mut sorted = [(&PLACEHOLDER, ""); N];
+    let mut len = 0;
+    let mut i = 0;
+
+    while i < records.len() {
+        let mut pos = 0;
+        let mut duplicate = false;
+
+        while pos < len {
+            match cmp_ber(sorted[pos].0.as_bytes(), records[i].0.as_bytes()) {
+                Ordering::Less => pos += 1,
+                Ordering::Equal => {
+                    duplicate = true;
+                    break;
+                }
+                Ordering::Greater => break,
+            }
+        }
+
+        if !duplicate {
+            let mut j = len;
+
+            while j > pos {
+                sorted[j] = sorted[j - 1];
+                j -= 1;
+            }
+
+            sorted[pos] = records[i];
+            len += 1;
+        }
+
+        i += 1;
+    }
+
+    sorted
+}
+
 /// A query interface for OIDs/Names.
+///
+/// Besides the records themselves, generated databases include the first name of each OID in a
+/// table sorted by OID, so that lookups by OID are a binary search rather than a linear scan.
 #[derive(Copy, Clone)]
-pub struct Database<'a>(&'a [(&'a ObjectIdentifier, &'a str)]);
+pub struct Database<'a>(
+    &'a [(&'a ObjectIdentifier, &'a str)],
+    &'a [(
+        &'a ObjectIdentifier,
+        &'a ObjectIdentifier,
+        &'a ObjectIdentifier,
+    )],
+    &'a [(&'a ObjectIdentifier, &'a str)],
+    &'a [(&'a ObjectIdentifier, &'a str)],
+);
 
 impl<'a> Database<'a> {
+    /// Create a custom database from a list of OIDs and their names.
+    ///
+    /// See also the [`oid_db!`][`crate::oid_db`] macro.
+    pub const fn new(records: &'a [(&'a ObjectIdentifier, &'a str)]) -> Self {
+        Self(records, &[], &[], &[])
+    }
+
+    /// Create a custom database from a list of OIDs and their names along with the first name
+    /// of each OID sorted by OID, as built by [`sort_records`].
+    ///
+    /// Used by the [`oid_db!`][`crate::oid_db`] macro.
+    #[doc(hidden)]
+    pub const fn new_sorted(
+        records: &'a [(&'a ObjectIdentifier, &'a str)],
+        sorted: &'a [(&'a ObjectIdentifier, &'a str)],
+    ) -> Self {
+        Self(records, &[], &[], sorted)
+    }
+
     /// Looks up a name for an OID.
     ///
     /// Errors if the input is not a valid OID.
@@ -52,7 +211,13 @@ impl<'a> Database<'a> {
     }
 
     /// Finds a named oid by its associated OID.
+    ///
+    /// If the OID has several names, the first listed is returned.
     pub const fn by_oid(&self, oid: &ObjectIdentifier) -> Option<&'a str> {
+        if !self.3.is_empty() {
+            return self.by_oid_sorted(oid);
+        }
+
         let mut i = 0;
 
         while i < self.0.len() {
@@ -68,6 +233,30 @@ impl<'a> Database<'a> {
         None
     }
 
+    /// Does this database have a name for the given OID, e.g. to decide whether to render it
+    /// by name or numerically?
+    pub const fn is_known(&self, oid: &ObjectIdentifier) -> bool {
+        self.by_oid(oid).is_some()
+    }
+
+    /// Binary search the OID-sorted table for the name of an OID.
+    const fn by_oid_sorted(&self, oid: &ObjectIdentifier) -> Option<&'a str> {
+        let mut low = 0;
+        let mut high = self.3.len();
+
+        while low < high {
+            let mid = low + (high - low) / 2;
+
+            match cmp_ber(self.3[mid].0.as_bytes(), oid.as_bytes()) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Some(self.3[mid].1),
+            }
+        }
+
+        None
+    }
+
     /// Finds a named oid by its associated name.
     pub const fn by_name(&self, name: &str) -> Option<&'a ObjectIdentifier> {
         let mut i = 0;
@@ -84,6 +273,114 @@ impl<'a> Database<'a> {
         None
     }
 
+    /// Finds an OID with the given name (ignoring case) which also satisfies `predicate`, e.g.
+    /// to disambiguate names which are defined under more than one arc.
+    pub(crate) fn find_by_name(
+        &self,
+        name: &str,
+        predicate: impl Fn(&ObjectIdentifier) -> bool,
+    ) -> Option<&'a ObjectIdentifier> {
+        self.0
+            .iter()
+            .find(|(oid, lhs)| eq_case(lhs.as_bytes(), name.as_bytes()) && predicate(oid))
+            .map(|(oid, _)| *oid)
+    }
+
+    /// Decompose a composite signature algorithm OID (e.g. `sha256WithRSAEncryption`) into the
+    /// OIDs of its digest and public key algorithms, in that order.
+    ///
+    /// EdDSA signature OIDs decompose into the digest used by the algorithm and the signature
+    /// OID itself, which also identifies the public key.
+    pub const fn decompose_signature_alg(
+        &self,
+        oid: &ObjectIdentifier,
+    ) -> Option<(ObjectIdentifier, ObjectIdentifier)> {
+        let mut i = 0;
+
+        while i < self.1.len() {
+            let (sig, digest, pk) = self.1[i];
+
+            if sig.ber.eq(&oid.ber) {
+                return Some((*digest, *pk));
+            }
+
+            i += 1;
+        }
+
+        None
+    }
+
+    /// Finds the name of the elliptic curve with the given OID, e.g. `P-256` or `secp256k1`.
+    ///
+    /// NIST curves are named as in FIPS 186 (e.g. `P-256` rather than `secp256r1`).
+    pub const fn curve_name_by_oid(&self, oid: &ObjectIdentifier) -> Option<&'a str> {
+        let mut i = 0;
+
+        while i < self.2.len() {
+            let (curve, name) = self.2[i];
+
+            if curve.ber.eq(&oid.ber) {
+                return Some(name);
+            }
+
+            i += 1;
+        }
+
+        None
+    }
+
+    /// Finds the longest prefix of the given OID (including the OID itself) which has a name,
+    /// e.g. for rendering `2.5.4.3.1` as `cn.1`.
+    ///
+    /// Returns the name, the named prefix, and the remaining arcs of `oid` which follow it.
+    pub fn longest_named_prefix<'o>(
+        &self,
+        oid: &'o ObjectIdentifier,
+    ) -> Option<(&'a str, ObjectIdentifier, Arcs<'o>)> {
+        let mut best: Option<(&'a ObjectIdentifier, &'a str)> = None;
+
+        for &(candidate, name) in self.0 {
+            let longer = match best {
+                Some((prefix, _)) => candidate.as_bytes().len() > prefix.as_bytes().len(),
+                None => true,
+            };
+
+            if longer && oid.starts_with(*candidate) {
+                best = Some((candidate, name));
+            }
+        }
+
+        let (prefix, name) = best?;
+        let mut remaining = oid.arcs();
+
+        for _ in prefix.arcs() {
+            remaining.next();
+        }
+
+        Some((name, *prefix, remaining))
+    }
+
+    /// Get all available information about an OID.
+    ///
+    /// Returns `None` if the OID has no names in this database.
+    pub fn info(&self, oid: &ObjectIdentifier) -> Option<OidInfo<'a>> {
+        let all_names = self.find_names_for_oid(*oid);
+        let mut names = all_names.clone();
+        let primary_name = names.next()?;
+        let short_name = names
+            .chain(Some(primary_name))
+            .min_by_key(|name| name.len())
+            .filter(|name| name.len() < primary_name.len());
+
+        Some(OidInfo {
+            oid: *oid,
+            primary_name,
+            all_names,
+            short_name,
+            description: None,
+        })
+    }
+
     /// Return the list of matched name for the OID.
     pub const fn find_names_for_oid(&self, oid: ObjectIdentifier) -> Names<'a> {
         Names {
@@ -94,7 +391,96 @@ impl<'a> Database<'a> {
     }
 }
 
+/// Finds the OID corresponding to an OpenSSL numeric identifier (NID), e.g. `NID_sha256`.
+///
+/// Only a curated subset of commonly used NIDs is supported.
+#[cfg(feature = "openssl-nid")]
+pub const fn nid_to_oid(nid: i32) -> Option<ObjectIdentifier> {
+    let mut i = 0;
+
+    while i < NIDS.len() {
+        if NIDS[i].0 == nid {
+            return Some(NIDS[i].1);
+        }
+
+        i += 1;
+    }
+
+    None
+}
+
+/// Finds the OpenSSL numeric identifier (NID) corresponding to an OID.
+///
+/// Only a curated subset of commonly used NIDs is supported.
+#[cfg(feature = "openssl-nid")]
+pub const fn oid_to_nid(oid: &ObjectIdentifier) -> Option<i32> {
+    let mut i = 0;
+
+    while i < NIDS.len() {
+        if NIDS[i].1.ber.eq(&oid.ber) {
+            return Some(NIDS[i].0);
+        }
+
+        i += 1;
+    }
+
+    None
+}
+
+/// Define a custom [`Database`] of OIDs and their names.
+///
+/// OIDs are parsed at compile time using [`ObjectIdentifier::new_unwrap`], so invalid OIDs are a
+/// compile-time error. The records need not be in any particular order: they are also sorted by
+/// OID at compile time, so that lookups by OID are a binary search as with [`DB`].
+///
+/// ```
+/// use const_oid::{db::Database, oid_db};
+///
+/// const MY_DB: Database<'static> = oid_db! {
+///     "myAlgorithm" => "1.3.6.1.4.1.55555.1",
+///     "myOtherAlgorithm" => "1.3.6.1.4.1.55555.2",
+/// };
+///
+/// assert_eq!(MY_DB.by_name("myAlgorithm").unwrap().to_string(), "1.3.6.1.4.1.55555.1");
+/// ```
+#[macro_export]
+macro_rules! oid_db {
+    ($($name:expr => $oid:expr),* $(,)?) => {{
+        const RECORDS: &[(&$crate::ObjectIdentifier, &str)] = &[
+            $((&$crate::ObjectIdentifier::new_unwrap($oid), $name)),*
+        ];
+        const SORTED: [(&$crate::ObjectIdentifier, &str); $crate::db::count_oids(RECORDS)] =
+            $crate::db::sort_records(RECORDS);
+        $crate::db::Database::new_sorted(RECORDS, &SORTED)
+    }};
+}
+
+/// Information about an OID in a [`Database`], as returned by [`Database::info`].
+///
+/// Names are borrowed from the database's tables.
+#[derive(Clone)]
+pub struct OidInfo<'a> {
+    /// The OID itself.
+    pub oid: ObjectIdentifier,
+
+    /// The first name listed for the OID.
+    pub primary_name: &'a str,
+
+    /// All names listed for the OID, including the primary name.
+    pub all_names: Names<'a>,
+
+    /// The shortest name listed for the OID (e.g. `cn` for `commonName`), if it is shorter
+    /// than the primary name.
+    pub short_name: Option<&'a str>,
+
+    /// A description of the OID.
+    ///
+    /// The generated tables carry no descriptions, so this is currently always `None`.
+    pub description: Option<&'a str>,
+}
+
 /// Iterator returning the multiple names that may be associated with an OID.
+#[derive(Clone)]
 pub struct Names<'a> {
     database: Database<'a>,
     oid: ObjectIdentifier,
@@ -137,6 +523,186 @@ mod tests {
         assert_eq!(None, super::DB.by_oid(&none));
     }
 
+    #[test]
+    fn by_oid_sorted() {
+        use super::{rfc5280, rfc5912, rfc8410, Database};
+
+        for (oid, name) in [
+            (rfc5912::RSA_ENCRYPTION, "rsaEncryption"),
+            (rfc5912::ID_SHA_256, "id-sha256"),
+            (rfc5912::ID_EC_PUBLIC_KEY, "id-ecPublicKey"),
+            (rfc5280::ID_CE_BASIC_CONSTRAINTS, "id-ce-basicConstraints"),
+            (rfc8410::ID_ED_25519, "id-Ed25519"),
+        ] {
+            assert_eq!(super::DB.by_oid(&oid), Some(name));
+        }
+
+        // Strictly sorted, so every OID appears once
+        let sorted = super::DB.3;
+        assert!(sorted
+            .windows(2)
+            .all(|w| super::cmp_ber(w[0].0.as_bytes(), w[1].0.as_bytes()).is_lt()));
+
+        // Consistent with a linear scan of the records, which returns the first name listed, so
+        // every OID in the records is also in the sorted table
+        let unsorted = Database::new(super::DB.0);
+
+        for (oid, _) in super::DB.0 {
+            assert_eq!(super::DB.by_oid(oid), unsorted.by_oid(oid), "{}", oid);
+        }
+    }
+
+    #[test]
+    fn oid_db_sorted() {
+        use super::Database;
+
+        const MY_DB: Database<'static> = crate::oid_db! {
+            "myOtherAlgorithm" => "1.3.6.1.4.1.55555.2",
+            "myAlgorithm" => "1.3.6.1.4.1.55555.1",
+            "myLongAlgorithm" => "1.3.6.1.4.1.55555.1.1",
+            "myAlgorithmAlias" => "1.3.6.1.4.1.55555.1",
+            "myShortAlgorithm" => "1.3.6.1.4.1.2",
+        };
+
+        assert!(MY_DB.3.iter().map(|(_, name)| *name).eq([
+            "myShortAlgorithm",
+            "myAlgorithm",
+            "myOtherAlgorithm",
+            "myLongAlgorithm"
+        ]));
+
+        // Lookups by OID return the first name listed, as with a linear scan
+        let unsorted = Database::new(MY_DB.0);
+
+        for (oid, _) in MY_DB.0 {
+            assert_eq!(MY_DB.by_oid(oid), unsorted.by_oid(oid), "{}", oid);
+        }
+    }
+
+    #[test]
+    fn decompose_signature_alg() {
+        use super::{rfc5912, rfc8410};
+
+        assert_eq!(
+            super::DB.decompose_signature_alg(&rfc5912::SHA_256_WITH_RSA_ENCRYPTION),
+            Some((rfc5912::ID_SHA_256, rfc5912::RSA_ENCRYPTION))
+        );
+        assert_eq!(
+            super::DB.decompose_signature_alg(&rfc5912::ECDSA_WITH_SHA_384),
+            Some((rfc5912::ID_SHA_384, rfc5912::ID_EC_PUBLIC_KEY))
+        );
+        assert_eq!(
+            super::DB.decompose_signature_alg(&rfc8410::ID_ED_25519),
+            Some((rfc5912::ID_SHA_512, rfc8410::ID_ED_25519))
+        );
+        assert_eq!(
+            super::DB.decompose_signature_alg(&rfc5912::RSA_ENCRYPTION),
+            None
+        );
+    }
+
+    #[test]
+    fn curve_name_by_oid() {
+        use super::rfc5912;
+
+        let curve_name = |oid| super::DB.curve_name_by_oid(&ObjectIdentifier::new_unwrap(oid));
+
+        assert_eq!(
+            super::DB.curve_name_by_oid(&rfc5912::SECP_256_R_1),
+            Some("P-256")
+        );
+        assert_eq!(
+            super::DB.curve_name_by_oid(&rfc5912::SECP_384_R_1),
+            Some("P-384")
+        );
+        assert_eq!(curve_name("1.3.132.0.10"), Some("secp256k1"));
+        assert_eq!(curve_name("1.3.36.3.3.2.8.1.1.7"), Some("brainpoolP256r1"));
+        assert_eq!(
+            super::DB.curve_name_by_oid(&rfc5912::ID_EC_PUBLIC_KEY),
+            None
+        );
+    }
+
+    #[test]
+    fn longest_named_prefix() {
+        let oid = ObjectIdentifier::new_unwrap("2.5.4.3.1.2");
+        let (name, prefix, remaining) = super::DB.longest_named_prefix(&oid).expect("no prefix");
+        assert_eq!(name, "cn");
+        assert_eq!(prefix, CN);
+        assert!(remaining.eq([1, 2]));
+
+        let (name, prefix, mut remaining) = super::DB.longest_named_prefix(&CN).expect("no prefix");
+        assert_eq!(name, "cn");
+        assert_eq!(prefix, CN);
+        assert_eq!(remaining.next(), None);
+
+        let oid = ObjectIdentifier::new_unwrap("2.5.4.99999");
+        let (name, prefix, remaining) = super::DB.longest_named_prefix(&oid).expect("no prefix");
+        assert_eq!(name, "id-at");
+        assert_eq!(prefix, ObjectIdentifier::new_unwrap("2.5.4"));
+        assert!(remaining.eq([99999]));
+
+        let none = ObjectIdentifier::new_unwrap("0.1.2.3.4.5.6.7.8.9");
+        assert!(super::DB.longest_named_prefix(&none).is_none());
+    }
+
+    #[cfg(feature = "openssl-nid")]
+    #[test]
+    fn openssl_nids() {
+        use super::{nid_to_oid, oid_to_nid, rfc5912};
+
+        // NID_rsaEncryption, NID_sha256, NID_sha256WithRSAEncryption, NID_X9_62_prime256v1
+        assert_eq!(nid_to_oid(6), Some(rfc5912::RSA_ENCRYPTION));
+        assert_eq!(nid_to_oid(672), Some(rfc5912::ID_SHA_256));
+        assert_eq!(nid_to_oid(668), Some(rfc5912::SHA_256_WITH_RSA_ENCRYPTION));
+        assert_eq!(nid_to_oid(415), Some(rfc5912::SECP_256_R_1));
+
+        assert_eq!(oid_to_nid(&rfc5912::RSA_ENCRYPTION), Some(6));
+        assert_eq!(oid_to_nid(&rfc5912::ID_SHA_256), Some(672));
+        assert_eq!(oid_to_nid(&rfc5912::ID_EC_PUBLIC_KEY), Some(408));
+
+        // NID_undef
+        assert_eq!(nid_to_oid(0), None);
+        assert_eq!(nid_to_oid(-1), None);
+        assert_eq!(
+            oid_to_nid(&ObjectIdentifier::new_unwrap("0.1.2.3.4.5.6.7.8.9")),
+            None
+        );
+    }
+
+    #[cfg(feature = "openssl-nid")]
+    #[test]
+    fn openssl_nids_round_trip() {
+        for (nid, oid) in super::NIDS {
+            assert_eq!(super::nid_to_oid(*nid), Some(*oid));
+            assert_eq!(super::oid_to_nid(oid), Some(*nid));
+        }
+    }
+
+    #[test]
+    fn info() {
+        use super::rfc4519;
+
+        let info = super::DB.info(&CN).expect("cn not found");
+        assert_eq!(info.oid, CN);
+        assert_eq!(info.primary_name, "cn");
+        assert_eq!(info.short_name, None);
+        assert_eq!(info.description, None);
+        assert!(info.all_names.clone().any(|name| name == "commonName"));
+        assert!(info
+            .all_names
+            .clone()
+            .all(|n| super::DB.by_name(n) == Some(&CN)));
+
+        let info = super::DB.info(&rfc4519::ST).expect("st not found");
+        assert_eq!(info.primary_name, "stateOrProvinceName");
+        assert_eq!(info.short_name, Some("st"));
+        assert!(info.all_names.eq(["stateOrProvinceName", "st"]));
+
+        let none = ObjectIdentifier::new_unwrap("0.1.2.3.4.5.6.7.8.9");
+        assert!(super::DB.info(&none).is_none());
+    }
+
     #[test]
     fn by_name() {
         let cn = super::DB.by_name("CN").expect("cn not found");
@@ -144,4 +710,50 @@ mod tests {
 
         assert_eq!(None, super::DB.by_name("purplePeopleEater"));
     }
+
+    #[test]
+    fn pkcs9_attributes() {
+        use super::{pkcs9, rfc2985};
+
+        for (name, oid) in [
+            ("contentType", pkcs9::CONTENT_TYPE),
+            ("messageDigest", pkcs9::MESSAGE_DIGEST),
+            ("signingTime", pkcs9::SIGNING_TIME),
+            ("counterSignature", pkcs9::COUNTER_SIGNATURE),
+            ("challengePassword", pkcs9::CHALLENGE_PASSWORD),
+            ("extensionRequest", pkcs9::EXTENSION_REQUEST),
+            ("friendlyName", pkcs9::FRIENDLY_NAME),
+            ("localKeyId", pkcs9::LOCAL_KEY_ID),
+            ("smimeCapabilities", pkcs9::SMIME_CAPABILITIES),
+            ("id-aa-contentHint", pkcs9::ID_AA_CONTENT_HINT),
+            ("id-aa-signingCertificate", pkcs9::ID_AA_SIGNING_CERTIFICATE),
+            (
+                "id-aa-signingCertificateV2",
+                pkcs9::ID_AA_SIGNING_CERTIFICATE_V_2,
+            ),
+            ("id-aa-binarySigningTime", pkcs9::ID_AA_BINARY_SIGNING_TIME),
+        ] {
+            assert_eq!(super::DB.by_name(name), Some(&oid));
+            assert_eq!(super::DB.by_oid(&oid), Some(name));
+        }
+
+        // The RFC 2985 ASN.1 module names still resolve
+        assert_eq!(
+            super::DB.by_name("pkcs-9-at-contentType"),
+            Some(&pkcs9::CONTENT_TYPE)
+        );
+        assert_eq!(rfc2985::PKCS_9_AT_SIGNING_TIME, pkcs9::SIGNING_TIME);
+        assert_eq!(
+            pkcs9::MESSAGE_DIGEST,
+            ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.4")
+        );
+        assert_eq!(
+            pkcs9::SMIME_CAPABILITIES,
+            ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.15")
+        );
+        assert_eq!(
+            pkcs9::ID_AA_SIGNING_CERTIFICATE_V_2,
+            ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.16.2.47")
+        );
+    }
 }
@@ -1,7 +1,7 @@
 //! OID encoder with `const` support.
 
 use crate::{
-    arcs::{ARC_MAX_FIRST, ARC_MAX_SECOND},
+    arcs::{RootArcs, ARC_MAX_FIRST},
     Arc, Buffer, Error, ObjectIdentifier, Result,
 };
 
@@ -51,6 +51,10 @@ impl<const MAX_SIZE: usize> Encoder<MAX_SIZE> {
     }
 
     /// Encode an [`Arc`] as base 128 into the internal buffer.
+    ///
+    /// This is transactional: the space required for the entire arc is checked before anything
+    /// is written, and on error the encoder is consumed, so a partially encoded arc can never be
+    /// observed.
     pub(crate) const fn arc(mut self, arc: Arc) -> Result<Self> {
         match self.state {
             State::Initial => {
@@ -61,47 +65,53 @@ impl<const MAX_SIZE: usize> Encoder<MAX_SIZE> {
                 self.state = State::FirstArc(arc);
                 Ok(self)
             }
-            // Ensured not to overflow by `ARC_MAX_SECOND` check
-            #[allow(clippy::arithmetic_side_effects)]
-            State::FirstArc(first_arc) => {
-                if arc > ARC_MAX_SECOND {
-                    return Err(Error::ArcInvalid { arc });
+            // The first two arcs are encoded together as a single base 128 value
+            State::FirstArc(first_arc) => match RootArcs::new(first_arc, arc) {
+                Ok(root) => {
+                    self.state = State::Body;
+                    self.encode_arc(root.value())
                 }
+                Err(err) => Err(err),
+            },
+            State::Body => self.encode_arc(arc),
+        }
+    }
 
-                self.state = State::Body;
-                self.bytes[0] = (first_arc * (ARC_MAX_SECOND + 1)) as u8 + arc as u8;
-                self.cursor = 1;
-                Ok(self)
-            }
-            // TODO(tarcieri): finer-grained overflow safety / checked arithmetic
-            #[allow(clippy::arithmetic_side_effects)]
-            State::Body => {
-                // Total number of bytes in encoded arc - 1
-                let nbytes = base128_len(arc);
-
-                // Shouldn't overflow on any 16-bit+ architectures
-                if self.cursor + nbytes + 1 > MAX_SIZE {
-                    return Err(Error::Length);
-                }
+    /// Encode a single base 128 value at the current cursor position.
+    // TODO(tarcieri): finer-grained overflow safety / checked arithmetic
+    #[allow(clippy::arithmetic_side_effects)]
+    const fn encode_arc(self, arc: Arc) -> Result<Self> {
+        // Total number of bytes in encoded arc - 1
+        let nbytes = base128_len(arc);
+
+        // Shouldn't overflow on any 16-bit+ architectures
+        if self.cursor + nbytes + 1 > MAX_SIZE {
+            return Err(Error::Length);
+        }
 
-                let new_cursor = self.cursor + nbytes + 1;
+        let new_cursor = self.cursor + nbytes + 1;
 
-                // TODO(tarcieri): use `?` when stable in `const fn`
-                match self.encode_base128_byte(arc, nbytes, false) {
-                    Ok(mut encoder) => {
-                        encoder.cursor = new_cursor;
-                        Ok(encoder)
-                    }
-                    Err(err) => Err(err),
-                }
+        // TODO(tarcieri): use `?` when stable in `const fn`
+        match self.encode_base128_byte(arc, nbytes, false) {
+            Ok(mut encoder) => {
+                encoder.cursor = new_cursor;
+                Ok(encoder)
             }
+            Err(err) => Err(err),
         }
     }
 
     /// Finish encoding an OID.
     pub(crate) const fn finish(self) -> Result<ObjectIdentifier<MAX_SIZE>> {
-        if self.cursor == 0 {
-            return Err(Error::Empty);
+        match self.state {
+            State::Initial => return Err(Error::Empty),
+            State::FirstArc(_) => return Err(Error::NotEnoughArcs),
+            State::Body => (),
+        }
+
+        // The length of a `Buffer` is stored as a `u8`
+        if self.cursor > u8::MAX as usize {
+            return Err(Error::Length);
         }
 
         let ber = Buffer {
@@ -118,29 +128,73 @@ impl<const MAX_SIZE: usize> Encoder<MAX_SIZE> {
 
         // Underflow checked by branch
         #[allow(clippy::arithmetic_side_effects)]
-        if n > 0x80 {
+        if i > 0 {
             self.bytes[checked_add!(self.cursor, i)] = (n & 0b1111111) as u8 | mask;
             n >>= 7;
-
-            if i > 0 {
-                self.encode_base128_byte(n, i.saturating_sub(1), true)
-            } else {
-                Err(Error::Base128)
-            }
+            self.encode_base128_byte(n, i.saturating_sub(1), true)
         } else {
+            // `base128_len` ensures the remaining value fits in 7-bits
             self.bytes[self.cursor] = n as u8 | mask;
             Ok(self)
         }
     }
 }
 
+/// Encode the given arcs as the BER/DER value of an OID into `out`, returning the number of
+/// bytes written.
+///
+/// The arcs are validated the same way as [`ObjectIdentifier::from_arcs`], and
+/// [`Error::Length`] is returned if `out` is too small. On error the contents of `out` are
+/// unspecified.
+pub fn encode_arcs_into(arcs: &[Arc], out: &mut [u8]) -> Result<usize> {
+    let (first, second, rest) = match arcs {
+        [first, second, rest @ ..] => (*first, *second, rest),
+        [first] if *first > ARC_MAX_FIRST => return Err(Error::ArcInvalid { arc: *first }),
+        _ => return Err(Error::NotEnoughArcs),
+    };
+
+    if first > ARC_MAX_FIRST {
+        return Err(Error::ArcInvalid { arc: first });
+    }
+
+    let root = RootArcs::new(first, second)?;
+
+    if rest.is_empty() {
+        return Err(Error::NotEnoughArcs);
+    }
+
+    let mut cursor = write_base128(out, root.value())?;
+
+    for &arc in rest {
+        let (_, remaining) = out.split_at_mut(cursor);
+        cursor = checked_add!(cursor, write_base128(remaining, arc)?);
+    }
+
+    Ok(cursor)
+}
+
+/// Write a single base 128 value to the front of `out`, returning the number of bytes written.
+fn write_base128(out: &mut [u8], mut n: Arc) -> Result<usize> {
+    let nbytes = base128_len(n).saturating_add(1);
+    let bytes = out.get_mut(..nbytes).ok_or(Error::Length)?;
+    let mut mask = 0;
+
+    for byte in bytes.iter_mut().rev() {
+        *byte = (n & 0b1111111) as u8 | mask;
+        n >>= 7;
+        mask = 0b10000000;
+    }
+
+    Ok(nbytes)
+}
+
 /// Compute the length - 1 of an arc when encoded in base 128.
 const fn base128_len(arc: Arc) -> usize {
     match arc {
         0..=0x7f => 0,
         0x80..=0x3fff => 1,
         0x4000..=0x1fffff => 2,
-        0x200000..=0x1fffffff => 3,
+        0x200000..=0xfffffff => 3,
         _ => 4,
     }
 }
@@ -149,6 +203,7 @@ const fn base128_len(arc: Arc) -> usize {
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::Encoder;
+    use crate::Error;
     use hex_literal::hex;
 
     /// OID `1.2.840.10045.2.1` encoded as ASN.1 BER/DER
@@ -165,4 +220,87 @@ mod tests {
         let encoder = encoder.arc(1).unwrap();
         assert_eq!(&encoder.bytes[..encoder.cursor], EXAMPLE_OID_BER);
     }
+
+    #[test]
+    fn encode_minimal_at_thresholds() {
+        /// Encode `1.2.<arc>`, returning the encoding of `arc` alone.
+        fn encode(arc: u32) -> ([u8; 16], usize) {
+            let encoder = Encoder::<16>::new().arc(1).unwrap().arc(2).unwrap();
+            let encoder = encoder.arc(arc).unwrap();
+            assert_eq!(encoder.bytes[0], 0x2A);
+            (encoder.bytes, encoder.cursor)
+        }
+
+        for (arc, expected) in [
+            (0, &hex!("00")[..]),
+            (127, &hex!("7F")),
+            (128, &hex!("8100")),
+            (16383, &hex!("FF7F")),
+            (16384, &hex!("818000")),
+            (2097151, &hex!("FFFF7F")),
+            (2097152, &hex!("81808000")),
+            (268435455, &hex!("FFFFFF7F")),
+            (268435456, &hex!("8180808000")),
+            (u32::MAX, &hex!("8FFFFFFF7F")),
+        ] {
+            let (bytes, len) = encode(arc);
+            assert_eq!(&bytes[1..len], expected, "{}", arc);
+        }
+    }
+
+    #[test]
+    fn encode_minimal_root_arcs() {
+        for (first, second, expected) in [
+            (0, 0, &hex!("00")[..]),
+            (1, 39, &hex!("4F")),
+            (2, 47, &hex!("7F")),
+            (2, 48, &hex!("8100")),
+            (2, 999, &hex!("8837")),
+            (2, 16303, &hex!("FF7F")),
+            (2, 16304, &hex!("818000")),
+            (2, u32::MAX - 80, &hex!("8FFFFFFF7F")),
+        ] {
+            let encoder = Encoder::<8>::new().arc(first).unwrap().arc(second).unwrap();
+            assert_eq!(
+                &encoder.bytes[..encoder.cursor],
+                expected,
+                "{}.{}",
+                first,
+                second
+            );
+        }
+    }
+
+    #[test]
+    fn arc_at_capacity() {
+        let encoder = Encoder::<4>::new().arc(1).unwrap().arc(2).unwrap();
+        let encoder = encoder.arc(16384).unwrap();
+        assert_eq!(encoder.cursor, 4);
+
+        // No room for even a single byte arc
+        assert_eq!(encoder.arc(1).err().unwrap(), Error::Length);
+
+        let encoder = Encoder::<4>::new().arc(1).unwrap().arc(2).unwrap();
+        let encoder = encoder.arc(1).unwrap();
+
+        // Two free bytes, but the arc needs three
+        assert_eq!(encoder.arc(16384).err().unwrap(), Error::Length);
+    }
+
+    #[test]
+    fn finish_rejects_lengths_beyond_u8() {
+        /// Encode an OID consisting of a root arc byte followed by `n` single byte arcs.
+        fn encode(n: usize) -> Encoder<300> {
+            let mut encoder = Encoder::new().arc(1).unwrap().arc(2).unwrap();
+
+            for _ in 0..n {
+                encoder = encoder.arc(1).unwrap();
+            }
+
+            encoder
+        }
+
+        assert_eq!(encode(254).finish().unwrap().as_bytes().len(), 255);
+        assert_eq!(encode(255).finish().err().unwrap(), Error::Length);
+    }
 }
@@ -7,7 +7,10 @@ use core::fmt;
 pub type Result<T> = core::result::Result<T, Error>;
 
 /// OID errors.
-#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+///
+/// Errors are small `Copy` values, and so can be embedded in other error types without
+/// friction, e.g. as the `#[source]` of a `thiserror` variant.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub enum Error {
     /// Arc exceeds allowed range (i.e. for first or second OID)
     ArcInvalid {
@@ -15,6 +18,15 @@ pub enum Error {
         arc: Arc,
     },
 
+    /// Arc exceeds the caller-supplied maximum arc value.
+    ArcOutOfBounds {
+        /// Position of the arc within the OID.
+        index: usize,
+
+        /// Arc value that is erroneous.
+        arc: Arc,
+    },
+
     /// Arc is too big (exceeds 32-bit limits of this library).
     ///
     /// Technically the size of an arc is not constrained by X.660, however
@@ -22,8 +34,15 @@ pub enum Error {
     /// sufficient for PKIX/PKCS usages.
     ArcTooBig,
 
-    /// Base 128 encoding error (used in BER/DER serialization of arcs).
-    Base128,
+    /// Base 128 encoding error (used in BER/DER serialization of arcs), i.e. the input ends
+    /// partway through an arc.
+    Base128 {
+        /// Byte offset within the BER/DER serialization where the malformed arc begins.
+        offset: usize,
+    },
+
+    /// CBOR data item is not an OID encoded as described in RFC 9090.
+    CborInvalid,
 
     /// Expected a digit, but was provided something else.
     DigitExpected {
@@ -34,26 +53,105 @@ pub enum Error {
     /// Input data is empty.
     Empty,
 
+    /// OID-IRI label is neither a number nor a known name.
+    LabelInvalid,
+
+    /// Leading `.` character at start of input.
+    LeadingDot,
+
     /// OID length is invalid (too short or too long).
     Length,
 
+    /// OID has fewer than the 3 arcs required of all OIDs.
+    NotEnoughArcs,
+
+    /// OID is not within the subtree required by a policy.
+    OutsideSubtree,
+
+    /// Unexpected ASN.1 tag encountered when decoding DER.
+    TagInvalid {
+        /// Tag which was found instead of the expected one.
+        actual: u8,
+    },
+
+    /// OID contains fewer arcs than the caller-supplied count.
+    TooFewArcs,
+
+    /// OID contains more arcs than the caller-supplied limit.
+    TooManyArcs,
+
+    /// Unexpected trailing data following an encoded element.
+    TrailingData,
+
     /// Trailing `.` character at end of input.
     TrailingDot,
 }
 
 impl Error {
+    /// Get the [`ErrorKind`] which classifies this error.
+    pub const fn kind(self) -> ErrorKind {
+        match self {
+            Error::ArcInvalid { .. } | Error::ArcOutOfBounds { .. } | Error::OutsideSubtree => {
+                ErrorKind::ArcRange
+            }
+            Error::ArcTooBig => ErrorKind::Overflow,
+            Error::Base128 { .. }
+            | Error::CborInvalid
+            | Error::TagInvalid { .. }
+            | Error::TrailingData => ErrorKind::Encoding,
+            Error::DigitExpected { .. }
+            | Error::LabelInvalid
+            | Error::LeadingDot
+            | Error::TrailingDot => ErrorKind::Parse,
+            Error::Empty
+            | Error::Length
+            | Error::NotEnoughArcs
+            | Error::TooFewArcs
+            | Error::TooManyArcs => ErrorKind::Length,
+        }
+    }
+
     /// Escalate this error into a panic.
     ///
+    /// The message is prefixed with the [`ErrorKind`] (as rendered by its `Display` impl), and
+    /// the panic is attributed to the caller, e.g. of [`ObjectIdentifier::new_unwrap`].
+    ///
     /// This is a workaround until `Result::unwrap` is allowed in `const fn`.
+    ///
+    /// [`ObjectIdentifier::new_unwrap`]: crate::ObjectIdentifier::new_unwrap
     #[allow(clippy::panic)]
+    #[track_caller]
     pub(crate) const fn panic(self) -> ! {
         match self {
-            Error::ArcInvalid { .. } | Error::ArcTooBig => panic!("OID contains invalid arc"),
-            Error::Base128 => panic!("OID contains arc with invalid base 128 encoding"),
-            Error::DigitExpected { .. } => panic!("OID expected to start with digit"),
-            Error::Empty => panic!("OID value is empty"),
-            Error::Length => panic!("OID length invalid"),
-            Error::TrailingDot => panic!("OID ends with invalid trailing '.'"),
+            Error::ArcInvalid { .. } => panic!("arc out of range: OID contains out-of-range arc"),
+            Error::ArcOutOfBounds { .. } => {
+                panic!("arc out of range: OID contains arc exceeding the maximum")
+            }
+            Error::ArcTooBig => panic!("overflow: OID contains arc which is larger than 32-bits"),
+            Error::Base128 { .. } => {
+                panic!("encoding error: OID contains arc with invalid base 128 encoding")
+            }
+            Error::CborInvalid => panic!("encoding error: CBOR data item is not a tagged OID"),
+            Error::DigitExpected { .. } => panic!("parse error: OID expected to start with digit"),
+            Error::Empty => panic!("invalid length: OID value is empty"),
+            Error::LabelInvalid => {
+                panic!("parse error: OID-IRI contains invalid or unknown label")
+            }
+            Error::LeadingDot => panic!("parse error: OID starts with invalid leading '.'"),
+            Error::Length => panic!("invalid length: OID length invalid"),
+            Error::NotEnoughArcs => panic!("invalid length: OID must have at least 3 arcs"),
+            Error::OutsideSubtree => {
+                panic!("arc out of range: OID is outside the required subtree")
+            }
+            Error::TagInvalid { .. } => {
+                panic!("encoding error: OID encoded with unexpected ASN.1 tag")
+            }
+            Error::TooFewArcs => panic!("invalid length: OID has too few arcs"),
+            Error::TooManyArcs => panic!("invalid length: OID has too many arcs"),
+            Error::TrailingData => {
+                panic!("encoding error: encoded OID is followed by trailing data")
+            }
+            Error::TrailingDot => panic!("parse error: OID ends with invalid trailing '.'"),
         }
     }
 }
@@ -61,18 +159,165 @@ impl Error {
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            Error::ArcInvalid { arc } => write!(f, "OID contains out-of-range arc: {}", arc),
-            Error::ArcTooBig => f.write_str("OID contains arc which is larger than 32-bits"),
-            Error::Base128 => f.write_str("OID contains arc with invalid base 128 encoding"),
+            Error::ArcInvalid { arc } => write!(
+                f,
+                "OID contains out-of-range arc: {} (the first arc must be 0-2, and the second \
+                 0-39 unless the first arc is 2)",
+                arc
+            ),
+            Error::ArcOutOfBounds { index, arc } => write!(
+                f,
+                "OID arc {} at index {} exceeds the maximum arc value permitted by the profile",
+                arc, index
+            ),
+            Error::ArcTooBig => write!(
+                f,
+                "OID contains arc which is larger than 32-bits (the maximum is {})",
+                Arc::MAX
+            ),
+            Error::Base128 { offset } => write!(
+                f,
+                "OID contains arc with invalid base 128 encoding at byte offset {} (the last \
+                 byte of each arc must have its high bit clear)",
+                offset
+            ),
+            Error::CborInvalid => f.write_str(
+                "CBOR data item is not an OID (expected tag 111 enclosing a byte string, both \
+                 in their shortest encoding)",
+            ),
+            Error::DigitExpected { actual } if actual.is_ascii_graphic() => {
+                write!(f, "expected digit (0-9), got '{}'", char::from(actual))
+            }
             Error::DigitExpected { actual } => {
-                write!(f, "expected digit, got '{}'", char::from(actual))
+                write!(f, "expected digit (0-9), got byte 0x{:02X}", actual)
             }
             Error::Empty => f.write_str("OID value is empty"),
-            Error::Length => f.write_str("OID length invalid"),
-            Error::TrailingDot => f.write_str("OID ends with invalid trailing '.'"),
+            Error::LabelInvalid => f.write_str(
+                "OID-IRI contains invalid or unknown label (each label must be a number or \
+                 a name in the database)",
+            ),
+            Error::LeadingDot => {
+                f.write_str("OID starts with invalid leading '.' (OIDs must start with a digit)")
+            }
+            Error::Length => write!(
+                f,
+                "OID length invalid (the BER/DER encoding must fit in the buffer, and is at \
+                 most {} bytes)",
+                u8::MAX
+            ),
+            Error::NotEnoughArcs => f.write_str(
+                "OID must have at least 3 arcs, e.g. `1.2.3` (an arc on its own is not an OID)",
+            ),
+            Error::OutsideSubtree => f.write_str(
+                "OID is outside the required subtree (it must extend the subtree's root with at \
+                 least one arc)",
+            ),
+            Error::TagInvalid { actual } => write!(
+                f,
+                "unexpected ASN.1 tag: 0x{:02X} (OIDs use tag 0x06, and sequences of OIDs \
+                 0x30)",
+                actual
+            ),
+            Error::TooFewArcs => {
+                f.write_str("OID has fewer arcs than the count expected when decoding it")
+            }
+            Error::TooManyArcs => {
+                f.write_str("OID has more arcs than the limit given when decoding it")
+            }
+            Error::TrailingData => f.write_str(
+                "encoded OID is followed by trailing data (the input must contain exactly \
+                 one element)",
+            ),
+            Error::TrailingDot => f.write_str(
+                "OID ends with invalid trailing '.' (remove it, or add the missing arc)",
+            ),
         }
     }
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for Error {}
+
+impl From<Error> for ErrorKind {
+    fn from(err: Error) -> ErrorKind {
+        err.kind()
+    }
+}
+
+/// Decoding errors are reported as [`std::io::ErrorKind::InvalidData`], with the [`Error`] as
+/// the source, e.g. for readers which decode OIDs from a stream.
+#[cfg(feature = "std")]
+impl From<Error> for std::io::Error {
+    fn from(err: Error) -> std::io::Error {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, err)
+    }
+}
+
+/// Error parsing one element of a list of OIDs.
+///
+/// Returned by [`ObjectIdentifier::parse_list`][`crate::ObjectIdentifier::parse_list`].
+#[cfg(feature = "alloc")]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ParseListError {
+    /// Index of the element which failed to parse (ignoring empty elements).
+    pub index: usize,
+
+    /// Error parsing the element.
+    pub error: Error,
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for ParseListError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "OID list element {}: {}", self.index, self.error)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<ParseListError> for Error {
+    fn from(err: ParseListError) -> Error {
+        err.error
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseListError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Coarse-grained classification of [`Error`] values.
+///
+/// Unlike [`Error`], these kinds carry no data and are stable across versions,
+/// which makes them suitable for programmatic error handling.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Arc is outside the range permitted at its position in the OID.
+    ArcRange,
+
+    /// Malformed BER/DER encoding.
+    Encoding,
+
+    /// OID is empty, too short, or too long.
+    Length,
+
+    /// Value exceeds the limits supported by this library.
+    Overflow,
+
+    /// Malformed dot-delimited string.
+    Parse,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ErrorKind::ArcRange => "arc out of range",
+            ErrorKind::Encoding => "encoding error",
+            ErrorKind::Length => "invalid length",
+            ErrorKind::Overflow => "overflow",
+            ErrorKind::Parse => "parse error",
+        })
+    }
+}
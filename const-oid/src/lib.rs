@@ -19,6 +19,8 @@
     unused_qualifications
 )]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
@@ -27,23 +29,59 @@ mod checked;
 
 mod arcs;
 mod buffer;
+mod builder;
+#[cfg(feature = "cbor")]
+mod cbor;
 mod encoder;
 mod error;
+mod map;
 mod parser;
+mod pattern;
+mod report;
+mod schema;
+mod tlv;
 mod traits;
 
+#[cfg(feature = "alloc")]
+mod set;
+#[cfg(feature = "alloc")]
+mod tree;
+
 #[cfg(feature = "db")]
 pub mod db;
 
+#[cfg(feature = "alloc")]
+pub use crate::error::ParseListError;
+#[cfg(feature = "alloc")]
+pub use crate::set::OidSet;
+#[cfg(feature = "winnow")]
+pub use crate::tlv::parse_der;
+#[cfg(feature = "alloc")]
+pub use crate::tree::{OidTree, OidTreeNode, OidTreeWalk};
+
 pub use crate::{
-    arcs::{Arc, Arcs},
+    arcs::{Arc, Arcs, ByteGroups, RootAuthority},
     buffer::Buffer,
-    error::{Error, Result},
-    traits::{AssociatedOid, DynAssociatedOid},
+    builder::OidBuilder,
+    encoder::encode_arcs_into,
+    error::{Error, ErrorKind, Result},
+    map::OidMap,
+    pattern::OidPattern,
+    report::ParseReport,
+    schema::{ArcConstraint, OidSchema, SchemaError},
+    tlv::SequenceOf,
+    traits::{oid_of, AssociatedOid, AssociatedOids, DynAssociatedOid},
 };
 
 use crate::encoder::Encoder;
-use core::{borrow::Borrow, fmt, ops::Deref, str::FromStr};
+use core::{
+    borrow::Borrow,
+    fmt,
+    hash::{Hash, Hasher},
+    iter,
+    ops::Deref,
+    str::FromStr,
+};
 
 /// Default maximum size.
 ///
@@ -62,10 +100,12 @@ const DEFAULT_MAX_SIZE: usize = 39;
 ///
 /// - The OID MUST have at least 3 arcs
 /// - The first arc MUST be within the range 0-2
-/// - The second arc MUST be within the range 0-39
+/// - The second arc MUST be within the range 0-39 when the first arc is 0 or 1. When the first
+///   arc is 2 it may be any value (e.g. `2.999`), in which case the BER encoding of the first two
+///   arcs spans multiple bytes
 /// - The BER/DER encoding of the OID MUST be shorter than
 ///   [`ObjectIdentifier::MAX_SIZE`]
-#[derive(Clone, Copy, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord)]
 pub struct ObjectIdentifier<const MAX_SIZE: usize = DEFAULT_MAX_SIZE> {
     /// Buffer containing BER/DER-serialized bytes (sans ASN.1 tag/length)
     ber: Buffer<MAX_SIZE>,
@@ -92,7 +132,13 @@ impl ObjectIdentifier {
     /// `ObjectIdentifier::new(...).unwrap()`.
     ///
     /// Use [`ObjectIdentifier::new`] for fallible parsing.
+    ///
+    /// # Panics
+    ///
+    /// If `s` is not a valid OID, with a message starting with the [`ErrorKind`] of the error,
+    /// e.g. `invalid length: OID value is empty`. In const contexts this is a compile error.
     // TODO(tarcieri): remove this when `Result::unwrap` is `const fn`
+    #[track_caller]
     pub const fn new_unwrap(s: &str) -> Self {
         match Self::new(s) {
             Ok(oid) => oid,
@@ -101,6 +147,19 @@ impl ObjectIdentifier {
     }
 
     /// Parse an [`ObjectIdentifier`] from the dot-delimited string form.
+    ///
+    /// This never panics: all malformed inputs, including arcs which overflow an [`Arc`], are
+    /// reported as an [`Error`]. Stack usage is also independent of the length of the input.
+    ///
+    /// # Errors
+    ///
+    /// Degenerate inputs are classified as follows:
+    ///
+    /// - `""`: [`Error::Empty`]
+    /// - `"."`, `".1"`: [`Error::LeadingDot`]
+    /// - `"1"`: [`Error::NotEnoughArcs`]
+    /// - `"1."`: [`Error::TrailingDot`]
+    /// - `"1..2"`: [`Error::DigitExpected`] (with `actual` set to `.`)
     pub const fn new(s: &str) -> Result<Self> {
         // TODO(tarcieri): use `?` when stable in `const fn`
         match parser::Parser::parse(s) {
@@ -109,21 +168,434 @@ impl ObjectIdentifier {
         }
     }
 
+    /// Parse an [`ObjectIdentifier`] from the dot-delimited string form, allowing ASCII
+    /// underscores as digit separators within arcs, e.g. `1.2.840.113_549`.
+    ///
+    /// Underscores must appear between two digits. Unlike [`ObjectIdentifier::new`] this is not
+    /// a `const fn`.
+    pub fn new_lenient(s: &str) -> Result<Self> {
+        parser::parse_arcs(Encoder::new(), s, parser::parse_arc_lenient)?.finish()
+    }
+
+    /// Parse an [`ObjectIdentifier`] from the dot-delimited string form, additionally allowing
+    /// the first arc to be given as the name of a well-known root, as is common in SMI MIB
+    /// files, e.g. `iso.3.6.1` for `1.3.6.1`.
+    ///
+    /// See [`RootAuthority::from_name`] for the supported names.
+    pub fn new_symbolic(s: &str) -> Result<Self> {
+        let (head, tail) = s.split_once('.').unwrap_or((s, ""));
+
+        match RootAuthority::from_name(head) {
+            Some(root) => {
+                if s.ends_with('.') {
+                    return Err(Error::TrailingDot);
+                }
+
+                // A bare root name like `iso` is a single arc, not an empty OID
+                if tail.is_empty() {
+                    return Err(Error::NotEnoughArcs);
+                }
+
+                let encoder = Encoder::new().arc(root.arc())?;
+                parser::parse_arcs(encoder, tail, parser::parse_arc)?.finish()
+            }
+            None => Self::new(s),
+        }
+    }
+
+    /// Parse an [`ObjectIdentifier`] from an iterator over the ASCII bytes of the dot-delimited
+    /// string form, e.g. when reading the input incrementally.
+    ///
+    /// Performs the same validation as [`ObjectIdentifier::new`].
+    pub fn from_ascii_iter(iter: impl Iterator<Item = u8>) -> Result<Self> {
+        let mut encoder = Encoder::new();
+        let mut empty = true;
+
+        // Arc currently being parsed, or `None` if no digits have been seen since the last dot
+        let mut current_arc: Option<Arc> = None;
+
+        for byte in iter {
+            let leading = empty;
+            empty = false;
+
+            match byte {
+                b'0'..=b'9' => {
+                    let digit = byte.saturating_sub(b'0');
+                    let arc = current_arc
+                        .unwrap_or_default()
+                        .checked_mul(10)
+                        .and_then(|arc| arc.checked_add(digit as Arc))
+                        .ok_or(Error::ArcTooBig)?;
+
+                    current_arc = Some(arc);
+                }
+                b'.' => {
+                    let arc = match current_arc.take() {
+                        Some(arc) => arc,
+                        None if leading => return Err(Error::LeadingDot),
+                        None => return Err(Error::DigitExpected { actual: b'.' }),
+                    };
+
+                    encoder = encoder.arc(arc)?;
+                }
+                actual => return Err(Error::DigitExpected { actual }),
+            }
+        }
+
+        match current_arc {
+            Some(arc) => encoder.arc(arc)?.finish(),
+            None if empty => Err(Error::Empty),
+            None => Err(Error::TrailingDot),
+        }
+    }
+
+    /// Parse the dot-delimited OID at the start of `s`, returning it along with the remainder of
+    /// the string, e.g. the human-readable label in debug output like `2.5.4.3 (commonName)`.
+    ///
+    /// The OID ends at the first character which is neither an ASCII digit nor `.`, and must be
+    /// valid on its own (e.g. it can't end with `.`). The remainder is returned as-is, including
+    /// any leading whitespace.
+    pub fn parse_prefix_numeric(s: &str) -> Result<(Self, &str)> {
+        let (prefix, rest) = s.split_at(
+            s.find(|c: char| !c.is_ascii_digit() && c != '.')
+                .unwrap_or(s.len()),
+        );
+
+        match (prefix.is_empty(), rest.bytes().next()) {
+            (true, Some(actual)) => Err(Error::DigitExpected { actual }),
+            _ => Ok((Self::new(prefix)?, rest)),
+        }
+    }
+
+    /// Parse an [`ObjectIdentifier`] from the dot-delimited string form, producing a detailed
+    /// [`ParseReport`] on parse errors.
+    ///
+    /// This is slower than [`ObjectIdentifier::new`], and intended for tooling (e.g. linters)
+    /// which needs to point out the offending portion of the input.
+    pub fn parse_with_report(s: &str) -> core::result::Result<Self, ParseReport> {
+        report::parse(s)
+    }
+
+    /// Parse a list of OIDs in dot-delimited string form, separated by commas and/or
+    /// whitespace, e.g. `"1.3.6.1.5.5.7.3.1, 1.3.6.1.5.5.7.3.2"`.
+    ///
+    /// Empty elements (e.g. from a trailing comma) are skipped. On failure, the returned error
+    /// includes the index of the element which failed to parse.
+    #[cfg(feature = "alloc")]
+    pub fn parse_list(s: &str) -> core::result::Result<alloc::vec::Vec<Self>, ParseListError> {
+        s.split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|element| !element.is_empty())
+            .enumerate()
+            .map(|(index, element)| {
+                Self::new(element).map_err(|error| ParseListError { index, error })
+            })
+            .collect()
+    }
+
+    /// Parse an [`ObjectIdentifier`] from the dot-delimited string form, returning `None` on
+    /// parse errors.
+    pub const fn new_ok(s: &str) -> Option<Self> {
+        match Self::new(s) {
+            Ok(oid) => Some(oid),
+            Err(_) => None,
+        }
+    }
+
+    /// Parse an [`ObjectIdentifier`] from the dot-delimited string form, returning the provided
+    /// `fallback` OID on parse errors.
+    pub const fn new_or(s: &str, fallback: Self) -> Self {
+        match Self::new(s) {
+            Ok(oid) => oid,
+            Err(_) => fallback,
+        }
+    }
+
     /// Parse an OID from a slice of [`Arc`] values (i.e. integers).
+    ///
+    /// Returns [`Error::NotEnoughArcs`] if fewer than 3 arcs are given.
     pub fn from_arcs(arcs: impl IntoIterator<Item = Arc>) -> Result<Self> {
         let mut encoder = Encoder::new();
+        let mut num_arcs = 0usize;
 
         for arc in arcs {
             encoder = encoder.arc(arc)?;
+            num_arcs = num_arcs.saturating_add(1);
+        }
+
+        if num_arcs < 3 {
+            return Err(Error::NotEnoughArcs);
+        }
+
+        encoder.finish()
+    }
+
+    /// Attempt to construct an OID from a single [`Arc`], which always fails with
+    /// [`Error::NotEnoughArcs`].
+    ///
+    /// OIDs must have at least 3 arcs, e.g. `1.2.3`: a lone number such as `42` is not an OID.
+    /// Use [`ObjectIdentifier::from_arcs`] or [`ObjectIdentifier::push_arc`] on a base OID
+    /// instead.
+    pub const fn from_single_arc(_arc: Arc) -> Result<Self> {
+        Err(Error::NotEnoughArcs)
+    }
+
+    /// Build an OID from a base OID and a dot-delimited suffix of additional arcs, e.g. joining
+    /// `1.2.840` with `113549.1.1` yields `1.2.840.113549.1.1`.
+    pub fn join(base: ObjectIdentifier, suffix: &str) -> Result<Self> {
+        parser::parse_arcs(Encoder::extend(base), suffix, parser::parse_arc)?.finish()
+    }
+
+    /// Get the longest common prefix of all of the given OIDs, i.e. their closest common
+    /// ancestor.
+    ///
+    /// Returns `None` if `oids` is empty or the common prefix has fewer than 3 arcs.
+    pub fn common_prefix_of(oids: &[ObjectIdentifier]) -> Option<Self> {
+        let (first, rest) = oids.split_first()?;
+
+        rest.iter()
+            .try_fold(first.common_prefix(first)?, |prefix, oid| {
+                prefix.common_prefix(oid)
+            })
+    }
+
+    /// Does the bundled database ([`db::DB`]) have a name for this OID?
+    ///
+    /// See [`db::Database::is_known`] to check against another database.
+    #[cfg(feature = "db")]
+    pub const fn is_well_known(&self) -> bool {
+        db::DB.is_known(self)
+    }
+
+    /// Render this OID in the OID-IRI form described in X.660, e.g. `/ISO/2/840/rsadsi/1/pkcs-1`.
+    ///
+    /// Each arc is labeled with the name the given database has for the OID ending in that arc,
+    /// if any, and is otherwise rendered numerically (which X.660 also permits). The first arc is
+    /// always labeled with its [`RootAuthority::iri_label`].
+    #[cfg(all(feature = "alloc", feature = "db"))]
+    pub fn to_oid_iri(&self, db: &db::Database<'_>) -> alloc::string::String {
+        use core::fmt::Write;
+
+        let mut iri = alloc::string::String::new();
+        let mut prefix: Option<Self> = None;
+
+        for (i, arc) in self.arcs().enumerate() {
+            iri.push('/');
+
+            prefix = match i {
+                0 => {
+                    iri.push_str(self.root_authority().iri_label());
+                    continue;
+                }
+                1 => Encoder::new()
+                    .arc(self.first_arc())
+                    .and_then(|encoder| encoder.arc(arc))
+                    .and_then(Encoder::finish)
+                    .ok(),
+                _ => prefix.and_then(|prefix| prefix.push_arc(arc).ok()),
+            };
+
+            match prefix.and_then(|prefix| db.by_oid(&prefix)) {
+                Some(name) => iri.push_str(name),
+                // Writing to a `String` is infallible
+                None => write!(iri, "{}", arc).unwrap_or_default(),
+            }
+        }
+
+        iri
+    }
+
+    /// Parse an OID from the OID-IRI form described in X.660, e.g.
+    /// `/ISO/2/840/rsadsi/1/pkcs-1/rsaEncryption`, as produced by [`ObjectIdentifier::to_oid_iri`].
+    ///
+    /// Each label is either a numeric arc or a name which the given database has for an OID
+    /// ending in that arc. The first label may also be a [`RootAuthority`] label (e.g. `ISO`) or
+    /// name (e.g. `iso`), which are resolved without the database.
+    ///
+    /// Returns [`Error::LabelInvalid`] if a label (including an empty one) can't be resolved.
+    #[cfg(feature = "db")]
+    pub fn from_oid_iri(s: &str, db: &db::Database<'_>) -> Result<Self> {
+        if s.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        let mut labels = s.strip_prefix('/').ok_or(Error::LabelInvalid)?.split('/');
+        let root = labels.next().ok_or(Error::LabelInvalid)?;
+
+        let first = [
+            RootAuthority::ItuT,
+            RootAuthority::Iso,
+            RootAuthority::JointIsoItuT,
+        ]
+        .into_iter()
+        .find(|authority| authority.iri_label() == root)
+        .or_else(|| RootAuthority::from_name(root))
+        .map(RootAuthority::arc)
+        .map_or_else(|| parser::parse_arc(root), Ok)
+        .map_err(|err| match err {
+            Error::ArcTooBig => err,
+            _ => Error::LabelInvalid,
+        })?;
+
+        let mut prefix: Option<Self> = None;
+
+        for (i, label) in labels.enumerate() {
+            // Index of the arc this label identifies
+            let index = i.saturating_add(1);
+
+            let arc = match parser::parse_arc(label) {
+                Ok(arc) => arc,
+                Err(Error::ArcTooBig) => return Err(Error::ArcTooBig),
+                Err(_) => db
+                    .find_by_name(label, |oid| {
+                        oid.len() == index.saturating_add(1)
+                            && match prefix {
+                                Some(prefix) => oid.starts_with(prefix),
+                                None => oid.first_arc() == first,
+                            }
+                    })
+                    .and_then(|oid| oid.arc(index))
+                    .ok_or(Error::LabelInvalid)?,
+            };
+
+            prefix = Some(match prefix {
+                Some(prefix) => prefix.push_arc(arc)?,
+                None => Encoder::new().arc(first)?.arc(arc)?.finish()?,
+            });
+        }
+
+        match prefix {
+            Some(oid) => Ok(oid),
+            None => Err(Error::NotEnoughArcs),
+        }
+    }
+
+    /// Construct an OID from its first and second arcs along with the remaining arcs.
+    ///
+    /// The first arc must be in the range 0-2, and the second arc in the range 0-39 unless the
+    /// first arc is 2, in which case it may be as large as the encoding allows, e.g. `2.999`.
+    pub const fn from_components(first: u8, second: Arc, rest: &[Arc]) -> Result<Self> {
+        // TODO(tarcieri): use `?` when stable in `const fn`
+        let mut encoder = match Encoder::new().arc(first as Arc) {
+            Ok(encoder) => match encoder.arc(second) {
+                Ok(encoder) => encoder,
+                Err(err) => return Err(err),
+            },
+            Err(err) => return Err(err),
+        };
+
+        let mut i = 0;
+        while i < rest.len() {
+            encoder = match encoder.arc(rest[i]) {
+                Ok(encoder) => encoder,
+                Err(err) => return Err(err),
+            };
+
+            // Won't overflow due to `i < rest.len()` check above
+            #[allow(clippy::arithmetic_side_effects)]
+            {
+                i += 1;
+            }
         }
 
         encoder.finish()
     }
 
     /// Parse an OID from from its BER/DER encoding.
+    ///
+    /// Note that this accepts BER encodings of arcs which are not minimal, i.e. which contain
+    /// leading `0x80` bytes. Such OIDs have the same arcs as their DER counterparts, but are not
+    /// byte-wise equal to them. Use [`ObjectIdentifier::canonical_bytes`] to obtain the DER encoding.
     pub fn from_bytes(ber_bytes: &[u8]) -> Result<Self> {
         ObjectIdentifierRef::from_bytes(ber_bytes)?.try_into()
     }
+
+    /// Parse an OID from BER/DER-encoded bytes, rejecting it with [`Error::TooManyArcs`] if it
+    /// contains more than `max_arcs` arcs.
+    ///
+    /// The limit is enforced while the input is validated, which bounds the work done on
+    /// pathologically deep OIDs received from untrusted sources.
+    pub fn from_bytes_bounded(ber_bytes: &[u8], max_arcs: usize) -> Result<Self> {
+        arcs::validate_bounded(ber_bytes, max_arcs)?;
+        ObjectIdentifierRef::from_bytes_unchecked(ber_bytes).try_into()
+    }
+
+    /// Parse an OID from its BER encoding, re-encoding any non-minimal arcs so the result is
+    /// canonical DER.
+    ///
+    /// Also returns whether the input was already minimal, e.g. to log non-canonical encodings,
+    /// which may indicate an attempt to evade byte-wise comparisons of OIDs. Inputs which exceed
+    /// the maximum size of an OID are accepted if their canonical encoding fits.
+    pub fn from_ber_canonical(ber_bytes: &[u8]) -> Result<(Self, bool)> {
+        let minimal = arcs::validate(ber_bytes)?;
+        let oid = ObjectIdentifierRef::from_bytes_unchecked(ber_bytes);
+
+        if minimal {
+            Ok((oid.try_into()?, true))
+        } else {
+            Ok((Self::from_arcs(oid.arcs())?, false))
+        }
+    }
+
+    /// Parse an OID from BER/DER-encoded bytes, requiring it to contain exactly `expected_arcs`
+    /// arcs, e.g. when a schema fixes the shape of the OID.
+    ///
+    /// Returns [`Error::TooManyArcs`] or [`Error::TooFewArcs`] if the number of arcs differs. As
+    /// with [`ObjectIdentifier::from_bytes_bounded`], decoding stops as soon as the OID is known
+    /// to contain too many arcs.
+    pub fn from_bytes_expecting(ber_bytes: &[u8], expected_arcs: usize) -> Result<Self> {
+        let oid = Self::from_bytes_bounded(ber_bytes, expected_arcs)?;
+
+        if oid.len() < expected_arcs {
+            return Err(Error::TooFewArcs);
+        }
+
+        Ok(oid)
+    }
+
+    /// Parse an OID from the first `value_len` bytes of BER/DER-encoded `bytes`, e.g. when the
+    /// length has already been decoded from a TLV header, returning the OID along with the
+    /// remaining bytes.
+    ///
+    /// Returns [`Error::Length`] if `value_len` exceeds the length of `bytes`.
+    pub fn from_value_prefix(bytes: &[u8], value_len: usize) -> Result<(Self, &[u8])> {
+        if value_len > bytes.len() {
+            return Err(Error::Length);
+        }
+
+        let (value, remaining) = bytes.split_at(value_len);
+        Ok((Self::from_bytes(value)?, remaining))
+    }
+
+    /// Parse a complete DER-encoded `OBJECT IDENTIFIER` element, i.e. including its tag and
+    /// length.
+    ///
+    /// Returns [`Error::TrailingData`] if any bytes follow the element. Use
+    /// [`ObjectIdentifier::from_der_prefix`] to parse an element followed by other data.
+    pub fn from_der(der: &[u8]) -> Result<Self> {
+        let (oid, remaining) = Self::from_der_prefix(der)?;
+
+        if !remaining.is_empty() {
+            return Err(Error::TrailingData);
+        }
+
+        Ok(oid)
+    }
+
+    /// Parse a DER-encoded `OBJECT IDENTIFIER` element from the front of `der`, returning the
+    /// OID along with the bytes following the element.
+    pub fn from_der_prefix(der: &[u8]) -> Result<(Self, &[u8])> {
+        let (value, remaining) = tlv::decode(tlv::OBJECT_IDENTIFIER_TAG, der)?;
+        Ok((Self::from_bytes(value)?, remaining))
+    }
+
+    /// Parse a DER-encoded `SEQUENCE OF OBJECT IDENTIFIER`, e.g. the contents of an X.509
+    /// extended key usage extension.
+    ///
+    /// The outer `SEQUENCE` header is validated eagerly, whereas each element is validated as
+    /// it is yielded by the returned [`SequenceOf`] iterator.
+    pub fn sequence_from_der(der: &[u8]) -> Result<SequenceOf<'_>> {
+        SequenceOf::new(der)
+    }
 }
 
 impl<const MAX_SIZE: usize> ObjectIdentifier<MAX_SIZE> {
@@ -131,15 +603,149 @@ impl<const MAX_SIZE: usize> ObjectIdentifier<MAX_SIZE> {
     ///
     /// Note that this encoding omits the ASN.1 tag/length, and only contains the value portion of
     /// the encoded OID.
+    #[inline]
     pub const fn as_bytes(&self) -> &[u8] {
         self.ber.as_bytes()
     }
 
+    /// Get the entire fixed-size backing array of this OID along with the length of its
+    /// BER/DER serialization, e.g. for comparing OIDs a block at a time.
+    ///
+    /// The first `len` bytes are the same as [`ObjectIdentifier::as_bytes`]. All bytes beyond
+    /// that are always zero padding.
+    #[inline]
+    pub const fn value_bytes(&self) -> (&[u8; MAX_SIZE], usize) {
+        (&self.ber.bytes, self.ber.len())
+    }
+
+    /// Get the tail of [`ObjectIdentifier::as_bytes`] following the first `prefix_len_bytes`
+    /// bytes, e.g. for delta-encoding sibling OIDs which share a common prefix.
+    ///
+    /// Note that the offset is measured in encoded bytes, not arcs. Returns an empty slice if the
+    /// offset is past the end of the encoding.
+    pub const fn value_suffix_after(&self, prefix_len_bytes: usize) -> &[u8] {
+        let bytes = self.as_bytes();
+
+        if prefix_len_bytes >= bytes.len() {
+            return &[];
+        }
+
+        bytes.split_at(prefix_len_bytes).1
+    }
+
+    /// Get the length of the complete DER encoding of this OID, including the ASN.1 tag and
+    /// length header.
+    ///
+    /// This is in contrast to `as_bytes().len()`, which is only the length of the value.
+    pub const fn der_len(&self) -> usize {
+        tlv::encoded_len(self.ber.len())
+    }
+
+    /// Write the complete DER encoding of this OID, including the ASN.1 tag and length header,
+    /// to the given writer.
+    ///
+    /// Returns the number of bytes written, i.e. [`ObjectIdentifier::der_len`].
+    #[cfg(feature = "std")]
+    pub fn write_der<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<usize> {
+        let (header, header_len) = tlv::encode_header(tlv::OBJECT_IDENTIFIER_TAG, self.ber.length);
+        writer.write_all(header.split_at(header_len).0)?;
+        writer.write_all(self.as_bytes())?;
+        Ok(self.der_len())
+    }
+
+    /// Get the complete DER encoding of this OID, including the ASN.1 tag and length header, as
+    /// an owned byte vector.
+    #[cfg(feature = "alloc")]
+    pub fn to_der_vec(&self) -> alloc::vec::Vec<u8> {
+        let (header, header_len) = tlv::encode_header(tlv::OBJECT_IDENTIFIER_TAG, self.ber.length);
+        let mut der = alloc::vec::Vec::with_capacity(self.der_len());
+        der.extend_from_slice(header.split_at(header_len).0);
+        der.extend_from_slice(self.as_bytes());
+        der
+    }
+
+    /// Get the canonical (i.e. minimal DER) encoding of this OID.
+    ///
+    /// This is the same as [`ObjectIdentifier::as_bytes`] unless the OID was parsed from a BER
+    /// encoding containing non-minimal arcs, in which case the arcs are re-encoded.
+    pub fn canonical_bytes(&self) -> Buffer<MAX_SIZE> {
+        let mut encoder = Encoder::new();
+
+        for arc in self.arcs() {
+            encoder = match encoder.arc(arc) {
+                Ok(encoder) => encoder,
+                // The canonical encoding is never longer than the original
+                Err(_) => return self.ber,
+            };
+        }
+
+        encoder.finish().map(|oid| oid.ber).unwrap_or(self.ber)
+    }
+
     /// Borrow an [`ObjectIdentifierRef`] which corresponds to this [`ObjectIdentifier`].
+    #[inline]
     pub const fn as_oid_ref(&self) -> &ObjectIdentifierRef {
         ObjectIdentifierRef::from_bytes_unchecked(self.as_bytes())
     }
 
+    /// Get the longest common prefix of this OID and another, i.e. their closest common ancestor
+    /// (which may be either OID itself).
+    ///
+    /// Returns `None` if the common prefix has fewer than 3 arcs.
+    pub fn common_prefix(&self, other: &ObjectIdentifierRef) -> Option<Self> {
+        let num_arcs = self.shared_arc_count(other);
+
+        if num_arcs < 3 {
+            return None;
+        }
+
+        let mut encoder = Encoder::new();
+
+        for arc in self.arcs().take(num_arcs) {
+            encoder = encoder.arc(arc).ok()?;
+        }
+
+        encoder.finish().ok()
+    }
+
+    /// Would the BER/DER encoding of this OID fit in an [`ObjectIdentifier`] with a `MAX_SIZE`
+    /// of `N`, i.e. will [`ObjectIdentifier::resize`] succeed?
+    pub const fn fits_in<const N: usize>(&self) -> bool {
+        self.ber.len() <= N
+    }
+
+    /// Copy this OID into an [`ObjectIdentifier`] with a different `MAX_SIZE`, e.g. to store
+    /// it in a smaller buffer.
+    ///
+    /// Returns [`Error::Length`] if the BER/DER encoding doesn't fit in `N` bytes.
+    pub const fn resize<const N: usize>(&self) -> Result<ObjectIdentifier<N>> {
+        let len = self.ber.len();
+
+        if len > N {
+            return Err(Error::Length);
+        }
+
+        let mut bytes = [0u8; N];
+        let mut i = 0;
+
+        while i < len {
+            bytes[i] = self.ber.bytes[i];
+
+            // Won't overflow due to `i < len` check above
+            #[allow(clippy::arithmetic_side_effects)]
+            {
+                i += 1;
+            }
+        }
+
+        let ber = Buffer {
+            bytes,
+            length: self.ber.length,
+        };
+
+        Ok(ObjectIdentifier { ber })
+    }
+
     /// Get the parent OID of this one (if applicable).
     pub fn parent(&self) -> Option<Self> {
         let num_arcs = self.len().checked_sub(1)?;
@@ -152,7 +758,63 @@ impl<const MAX_SIZE: usize> ObjectIdentifier<MAX_SIZE> {
         encoder.finish().ok()
     }
 
+    /// Remove the last arc of this OID, returning the parent OID along with the removed arc.
+    ///
+    /// Returns `None` if the parent would have fewer than 3 arcs.
+    pub fn pop_arc(&self) -> Option<(Self, Arc)> {
+        let num_arcs = self.len().checked_sub(1)?;
+
+        if num_arcs < 3 {
+            return None;
+        }
+
+        let mut arcs = self.arcs();
+        let mut encoder = Encoder::new();
+
+        for arc in arcs.by_ref().take(num_arcs) {
+            encoder = encoder.arc(arc).ok()?;
+        }
+
+        Some((encoder.finish().ok()?, arcs.next()?))
+    }
+
+    /// Build a new OID by mapping each arc after the first two with the given function, which
+    /// is passed the index of the arc within the OID along with its value.
+    ///
+    /// The first and second arcs are preserved as-is.
+    pub fn map_tail_arcs(&self, mut f: impl FnMut(usize, Arc) -> Arc) -> Result<Self> {
+        let mut encoder = Encoder::new();
+
+        for (i, arc) in self.arcs().enumerate() {
+            encoder = encoder.arc(if i < 2 { arc } else { f(i, arc) })?;
+        }
+
+        encoder.finish()
+    }
+
+    /// Build a new OID with the arc at the given index replaced by `value`, and all other arcs
+    /// preserved.
+    ///
+    /// Returns [`Error::Length`] if `index` is out of range, and [`Error::ArcInvalid`] if the
+    /// replacement violates the range rules for the first two arcs.
+    pub fn set_arc(&self, index: usize, value: Arc) -> Result<Self> {
+        if index >= self.len() {
+            return Err(Error::Length);
+        }
+
+        let mut encoder = Encoder::new();
+
+        for (i, arc) in self.arcs().enumerate() {
+            encoder = encoder.arc(if i == index { value } else { arc })?;
+        }
+
+        encoder.finish()
+    }
+
     /// Push an additional arc onto this OID, returning the child OID.
+    ///
+    /// Returns [`Error::Length`] if the arc doesn't fit in `MAX_SIZE` bytes. In this case no
+    /// part of the arc is encoded, i.e. a truncated OID is never produced.
     pub const fn push_arc(self, arc: Arc) -> Result<Self> {
         // TODO(tarcieri): use `?` when stable in `const fn`
         match Encoder::extend(self).arc(arc) {
@@ -161,37 +823,133 @@ impl<const MAX_SIZE: usize> ObjectIdentifier<MAX_SIZE> {
         }
     }
 
+    /// Parse a single decimal arc from a string and push it onto this OID, returning the child
+    /// OID.
+    pub fn push_arc_str(self, s: &str) -> Result<Self> {
+        if s.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        self.push_arc(parser::parse_arc(s)?)
+    }
+
+    /// Push an additional arc onto a copy of this OID, returning the child OID.
+    ///
+    /// Unlike [`ObjectIdentifier::push_arc`] this borrows `self`, leaving the original OID
+    /// available to retry with a different arc (or a larger `MAX_SIZE`) on failure.
+    pub const fn try_push_arc(&self, arc: Arc) -> Result<Self> {
+        // TODO(tarcieri): use `?` when stable in `const fn`
+        match Encoder::extend(*self).arc(arc) {
+            Ok(encoder) => encoder.finish(),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Push an additional arc onto this OID in place, e.g. when building an OID incrementally in
+    /// a loop.
+    ///
+    /// Returns [`Error::Length`] if the arc doesn't fit in `MAX_SIZE` bytes, in which case `self`
+    /// is left unchanged.
+    pub fn push_arc_mut(&mut self, arc: Arc) -> Result<()> {
+        match self.try_push_arc(arc) {
+            Ok(oid) => {
+                *self = oid;
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Append the given SNMP table index onto this OID (a conceptual row's entry OID), returning
+    /// the instance OID, e.g. appending `[1, 3]` to `ifEntry.ifDescr` for row `1.3`.
+    ///
+    /// Each element of `index` is a single sub-identifier, which is how SNMP encodes integer
+    /// index values (RFC 2578 section 7.7). Variable-length index values such as strings and OIDs
+    /// are encoded with one sub-identifier per byte or arc, preceded by their length unless the
+    /// index is `IMPLIED`, which callers must do when building `index`.
+    ///
+    /// Returns [`Error::Length`] if the instance OID doesn't fit in `MAX_SIZE` bytes.
+    pub fn append_snmp_index(self, index: &[Arc]) -> Result<Self> {
+        let mut encoder = Encoder::extend(self);
+
+        for &arc in index {
+            encoder = encoder.arc(arc)?;
+        }
+
+        encoder.finish()
+    }
+
     /// Does this OID start with the other OID?
+    #[inline]
     pub const fn starts_with<const SIZE: usize>(&self, other: ObjectIdentifier<SIZE>) -> bool {
-        let len = other.as_bytes().len();
+        let bytes = self.as_bytes();
+        let prefix = other.as_bytes();
 
-        if self.as_bytes().len() < len {
+        if bytes.len() < prefix.len() {
             return false;
         }
 
         let mut i = 0;
-        while i < len {
-            if self.as_bytes()[i] != other.as_bytes()[i] {
+
+        while i < prefix.len() {
+            if bytes[i] != prefix[i] {
                 return false;
             }
 
-            match i.checked_add(1) {
-                Some(succ) => i = succ,
-                None => return false,
+            // Won't overflow due to `i < prefix.len()` check above
+            #[allow(clippy::arithmetic_side_effects)]
+            {
+                i += 1;
             }
         }
 
         true
     }
+
+    /// Does this OID start with any of the given prefixes?
+    ///
+    /// Useful for evaluating allow/deny lists of OID subtrees, e.g. certificate policies.
+    pub fn matches_any_prefix<const SIZE: usize>(
+        &self,
+        prefixes: &[ObjectIdentifier<SIZE>],
+    ) -> bool {
+        self.first_matching_prefix(prefixes).is_some()
+    }
+
+    /// Get the first of the given prefixes (in slice order) which this OID starts with.
+    ///
+    /// Note that this is not necessarily the longest matching prefix when `prefixes` overlap.
+    pub fn first_matching_prefix<'p, const SIZE: usize>(
+        &self,
+        prefixes: &'p [ObjectIdentifier<SIZE>],
+    ) -> Option<&'p ObjectIdentifier<SIZE>> {
+        prefixes.iter().find(|prefix| self.starts_with(**prefix))
+    }
+
+    /// Require that this OID is located strictly below `root`, e.g. for a CA which only issues
+    /// policies under its own arc.
+    ///
+    /// Unlike [`ObjectIdentifier::starts_with`], `root` itself is not considered to be within
+    /// the subtree, and is rejected along with any other OID not starting with `root` by returning
+    /// [`Error::OutsideSubtree`].
+    pub fn require_under<const SIZE: usize>(&self, root: ObjectIdentifier<SIZE>) -> Result<()> {
+        if self.as_bytes().len() > root.as_bytes().len() && self.starts_with(root) {
+            Ok(())
+        } else {
+            Err(Error::OutsideSubtree)
+        }
+    }
 }
 
 impl<const MAX_SIZE: usize> AsRef<[u8]> for ObjectIdentifier<MAX_SIZE> {
+    #[inline]
     fn as_ref(&self) -> &[u8] {
         self.as_bytes()
     }
 }
 
 impl<const MAX_SIZE: usize> AsRef<ObjectIdentifierRef> for ObjectIdentifier<MAX_SIZE> {
+    #[inline]
     fn as_ref(&self) -> &ObjectIdentifierRef {
         self.as_oid_ref()
     }
@@ -203,6 +961,16 @@ impl<const MAX_SIZE: usize> Borrow<ObjectIdentifierRef> for ObjectIdentifier<MAX
     }
 }
 
+/// Hashes only the (length-prefixed) BER bytes rather than the entire backing buffer.
+///
+/// This is consistent with the `Hash` impl on [`ObjectIdentifierRef`], as the [`Borrow`] impl
+/// requires, and so also with OIDs of any other `MAX_SIZE`.
+impl<const MAX_SIZE: usize> Hash for ObjectIdentifier<MAX_SIZE> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_oid_ref().hash(state);
+    }
+}
+
 impl<const MAX_SIZE: usize> Deref for ObjectIdentifier<MAX_SIZE> {
     type Target = ObjectIdentifierRef;
 
@@ -219,6 +987,23 @@ impl FromStr for ObjectIdentifier {
     }
 }
 
+/// Compare against a dotted string, which is parsed as with [`ObjectIdentifier::new`].
+///
+/// Strings which fail to parse compare unequal.
+impl<const MAX_SIZE: usize> PartialEq<str> for ObjectIdentifier<MAX_SIZE> {
+    fn eq(&self, other: &str) -> bool {
+        parser::parse_arcs(Encoder::new(), other, parser::parse_arc)
+            .and_then(Encoder::finish)
+            .is_ok_and(|oid: Self| oid == *self)
+    }
+}
+
+impl<const MAX_SIZE: usize> PartialEq<&str> for ObjectIdentifier<MAX_SIZE> {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
 impl TryFrom<&[u8]> for ObjectIdentifier {
     type Error = Error;
 
@@ -227,13 +1012,32 @@ impl TryFrom<&[u8]> for ObjectIdentifier {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl TryFrom<&alloc::vec::Vec<u8>> for ObjectIdentifier {
+    type Error = Error;
+
+    fn try_from(ber_bytes: &alloc::vec::Vec<u8>) -> Result<Self> {
+        Self::from_bytes(ber_bytes)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<alloc::boxed::Box<[u8]>> for ObjectIdentifier {
+    type Error = Error;
+
+    fn try_from(ber_bytes: alloc::boxed::Box<[u8]>) -> Result<Self> {
+        Self::from_bytes(&ber_bytes)
+    }
+}
+
 impl<const MAX_SIZE: usize> TryFrom<&ObjectIdentifierRef> for ObjectIdentifier<MAX_SIZE> {
     type Error = Error;
 
     fn try_from(oid_ref: &ObjectIdentifierRef) -> Result<Self> {
         let len = oid_ref.as_bytes().len();
 
-        if len > MAX_SIZE {
+        // The length of a `Buffer` is stored as a `u8`
+        if len > MAX_SIZE || len > u8::MAX as usize {
             return Err(Error::Length);
         }
 
@@ -249,15 +1053,20 @@ impl<const MAX_SIZE: usize> TryFrom<&ObjectIdentifierRef> for ObjectIdentifier<M
     }
 }
 
+/// The alternate form (i.e. `{:#?}`) additionally shows the arcs and the hex-encoded value bytes.
 impl<const MAX_SIZE: usize> fmt::Debug for ObjectIdentifier<MAX_SIZE> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "ObjectIdentifier({})", self)
+        if f.alternate() {
+            debug_alternate("ObjectIdentifier", self.as_oid_ref(), f)
+        } else {
+            write!(f, "ObjectIdentifier({})", self)
+        }
     }
 }
 
 impl<const MAX_SIZE: usize> fmt::Display for ObjectIdentifier<MAX_SIZE> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.as_oid_ref())
+        fmt::Display::fmt(self.as_oid_ref(), f)
     }
 }
 
@@ -287,6 +1096,38 @@ impl<'a> arbitrary::Arbitrary<'a> for ObjectIdentifier {
     }
 }
 
+/// Zeroes the underlying [`Buffer`], leaving an empty OID.
+///
+/// An empty OID is not valid, so the value must not be used after `zeroize()` other than to be
+/// dropped or overwritten: e.g. displaying it or iterating over its arcs will panic.
+///
+/// `ObjectIdentifier` is `Copy`, so it can't impl `ZeroizeOnDrop`, and this crate provides no
+/// wrapper type of its own. Use [`zeroize::Zeroizing`], which zeroizes its contents on drop.
+#[cfg(feature = "zeroize")]
+impl<const MAX_SIZE: usize> zeroize::Zeroize for ObjectIdentifier<MAX_SIZE> {
+    fn zeroize(&mut self) {
+        self.ber.zeroize();
+    }
+}
+
+/// Find pairs of OIDs in a registry where one is a prefix of (or equal to) another, which makes
+/// the allocation ambiguous.
+///
+/// Yields `(prefix, descendant)` index pairs into `oids`, where `oids[descendant]` starts with
+/// `oids[prefix]`. Duplicate OIDs are reported in both orders.
+///
+/// This is quadratic in the number of OIDs and intended for auditing rather than hot paths.
+pub fn find_prefix_conflicts<const MAX_SIZE: usize>(
+    oids: &[ObjectIdentifier<MAX_SIZE>],
+) -> impl Iterator<Item = (usize, usize)> + '_ {
+    oids.iter().enumerate().flat_map(move |(i, prefix)| {
+        oids.iter()
+            .enumerate()
+            .filter(move |&(j, oid)| i != j && oid.starts_with(*prefix))
+            .map(move |(j, _)| (i, j))
+    })
+}
+
 /// OID reference type: wrapper for the BER serialization.
 #[derive(Eq, Hash, PartialEq, PartialOrd, Ord)]
 #[repr(transparent)]
@@ -301,13 +1142,13 @@ impl ObjectIdentifierRef {
     // TODO(tarcieri): `const fn` support
     pub fn from_bytes(ber: &[u8]) -> Result<&Self> {
         // Ensure arcs are well-formed
-        let mut arcs = Arcs::new(ber);
-        while arcs.try_next()?.is_some() {}
+        arcs::validate(ber)?;
         Ok(Self::from_bytes_unchecked(ber))
     }
 
     /// Create an [`ObjectIdentifierRef`] from the given byte slice without first checking that it
     /// contains valid BER/DER.
+    #[inline]
     pub(crate) const fn from_bytes_unchecked(ber: &[u8]) -> &Self {
         debug_assert!(!ber.is_empty());
 
@@ -322,6 +1163,7 @@ impl ObjectIdentifierRef {
     ///
     /// Note that this encoding omits the ASN.1 tag/length, and only contains the value portion of
     /// the encoded OID.
+    #[inline]
     pub const fn as_bytes(&self) -> &[u8] {
         &self.ber
     }
@@ -331,20 +1173,259 @@ impl ObjectIdentifierRef {
         self.arcs().nth(index)
     }
 
+    /// Return the arc with the given index, or `default` if the OID has no such arc.
+    pub fn arc_or(&self, index: usize, default: Arc) -> Arc {
+        self.arc(index).unwrap_or(default)
+    }
+
+    /// Get the first arc of this OID, i.e. `0` (ITU-T), `1` (ISO), or `2` (joint ISO/ITU-T).
+    pub fn first_arc(&self) -> Arc {
+        // Valid OIDs always have at least two arcs
+        self.arcs().next().unwrap_or_default()
+    }
+
+    /// Get the registration authority identified by the first arc of this OID.
+    pub fn root_authority(&self) -> RootAuthority {
+        // Valid OIDs always have a first arc in the range `0..=2`
+        RootAuthority::from_arc(self.first_arc()).unwrap_or(RootAuthority::JointIsoItuT)
+    }
+
+    /// Get the second arc of this OID.
+    pub fn second_arc(&self) -> Arc {
+        // Valid OIDs always have at least two arcs
+        self.arcs().nth(1).unwrap_or_default()
+    }
+
     /// Iterate over the arcs (a.k.a. nodes) of an [`ObjectIdentifier`].
     ///
     /// Returns [`Arcs`], an iterator over [`Arc`] values.
+    #[inline]
     pub fn arcs(&self) -> Arcs<'_> {
         Arcs::new(self.ber.as_ref())
     }
 
+    /// Iterate over the arcs of this OID starting from the arc at `start_index`, e.g. to dispatch
+    /// on the arcs following a known prefix.
+    ///
+    /// Equivalent to `self.arcs().skip(start_index)`, and yields nothing if `start_index` is past
+    /// the last arc.
+    pub fn arcs_from(&self, start_index: usize) -> impl Iterator<Item = Arc> + '_ {
+        self.arcs().skip(start_index)
+    }
+
+    /// Split this OID into its first arc, its second arc, and the remaining arcs, as accepted by
+    /// [`ObjectIdentifier::from_components`].
+    #[cfg(feature = "alloc")]
+    pub fn to_components(&self) -> (u8, Arc, alloc::vec::Vec<Arc>) {
+        // The first arc is at most 2
+        let first = self.first_arc() as u8;
+        (first, self.second_arc(), self.arcs_from(2).collect())
+    }
+
+    /// Write the dot-delimited string form of this OID to the given [`fmt::Write`], e.g. a
+    /// fixed-capacity string in `no_std` environments.
+    ///
+    /// Produces the same output as [`fmt::Display`], but formats each arc into a small stack
+    /// buffer and passes it to [`fmt::Write::write_str`] directly rather than going through a
+    /// [`fmt::Formatter`].
+    pub fn write_dotted<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        // Large enough for the decimal representation of `Arc::MAX`
+        let mut buf = [0u8; 10];
+
+        for (i, arc) in self.arcs().enumerate() {
+            if i > 0 {
+                w.write_char('.')?;
+            }
+
+            let mut n = arc;
+            let mut pos = buf.len();
+
+            loop {
+                pos = pos.checked_sub(1).ok_or(fmt::Error)?;
+                *buf.get_mut(pos).ok_or(fmt::Error)? = b'0' | (n % 10) as u8;
+                n /= 10;
+
+                if n == 0 {
+                    break;
+                }
+            }
+
+            let digits = buf.get(pos..).ok_or(fmt::Error)?;
+            w.write_str(core::str::from_utf8(digits).map_err(|_| fmt::Error)?)?;
+        }
+
+        Ok(())
+    }
+
+    /// Iterate over the arcs of this OID along with their index and whether each is the last arc,
+    /// i.e. `(index, arc, is_last)`, e.g. for rendering with separators.
+    pub fn arcs_enumerated(&self) -> impl Iterator<Item = (usize, Arc, bool)> + '_ {
+        let len = self.len();
+
+        self.arcs()
+            .enumerate()
+            .map(move |(i, arc)| (i, arc, i.checked_add(1) == Some(len)))
+    }
+
+    /// Fold over the arcs of this OID without collecting them, e.g. to compute a summary.
+    ///
+    /// Equivalent to `self.arcs().fold(init, f)`.
+    pub fn fold_arcs<B>(&self, init: B, f: impl FnMut(B, Arc) -> B) -> B {
+        self.arcs().fold(init, f)
+    }
+
+    /// Iterate over the base 128 byte groups of the BER/DER serialization of this OID, i.e. the
+    /// subslices of [`ObjectIdentifierRef::as_bytes`] which encode each arc.
+    ///
+    /// The first group encodes both the first and second arcs.
+    pub fn byte_groups(&self) -> ByteGroups<'_> {
+        ByteGroups::new(&self.ber)
+    }
+
     /// Get the length of this [`ObjectIdentifier`] in arcs.
     pub fn len(&self) -> usize {
         self.arcs().count()
     }
+
+    /// Compare the arcs of this OID with another OID's arcs.
+    ///
+    /// Unlike `==`, which compares the BER/DER serializations byte-for-byte, this compares the
+    /// decoded arc values, and therefore considers non-minimal BER encodings accepted by
+    /// [`ObjectIdentifier::from_bytes`] equal to their DER counterparts.
+    pub fn arcs_eq(&self, other: &Self) -> bool {
+        self.arcs().eq(other.arcs())
+    }
+
+    /// Are the arcs of this OID exactly the given arcs, e.g. when the expected OIDs of a dispatch
+    /// table are stored as arc arrays?
+    ///
+    /// This decodes the arcs of `self` rather than encoding `arcs`. A prefix of the arcs (or any
+    /// other slice of a different length) is not equal.
+    pub fn arcs_eq_slice(&self, arcs: &[Arc]) -> bool {
+        self.arcs().eq(arcs.iter().copied())
+    }
+
+    /// Count the leading arcs this OID has in common with another, stopping at the first arc
+    /// where they differ.
+    ///
+    /// Unlike [`ObjectIdentifier::common_prefix`] this doesn't construct the prefix, so any count
+    /// is possible, including those below the 3 arcs required of a valid OID.
+    pub fn shared_arc_count(&self, other: &Self) -> usize {
+        self.arcs()
+            .zip(other.arcs())
+            .take_while(|(a, b)| a == b)
+            .count()
+    }
+
+    /// Is the BER/DER serialization of this OID equal to the given bytes?
+    ///
+    /// Like [`ObjectIdentifierRef::as_bytes`], `other` should only contain the value portion of
+    /// the encoded OID.
+    pub fn eq_bytes(&self, other: impl AsRef<[u8]>) -> bool {
+        self.as_bytes() == other.as_ref()
+    }
+
+    /// Compute the CRC-32 (IEEE 802.3, as used by e.g. zlib) of the BER/DER serialization of
+    /// this OID, e.g. as a compact tag for referencing OIDs in a wire protocol.
+    ///
+    /// The result depends only on the encoded value, so it is stable across buffer sizes and
+    /// releases of this crate. Note that it is not unique: distinct OIDs may collide, which
+    /// becomes likely among tens of thousands of OIDs, so receivers should be able to detect or
+    /// tolerate collisions. It is also not a cryptographic hash, and must not be relied on where
+    /// an attacker can choose the OIDs.
+    pub const fn crc32_arcs(&self) -> u32 {
+        /// Reversed representation of the IEEE 802.3 polynomial.
+        const POLY: u32 = 0xEDB8_8320;
+
+        let bytes = self.as_bytes();
+        let mut crc = !0u32;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            crc ^= bytes[i] as u32;
+
+            let mut bit = 0;
+            while bit < 8 {
+                crc = if crc & 1 == 1 {
+                    (crc >> 1) ^ POLY
+                } else {
+                    crc >> 1
+                };
+
+                // Won't overflow due to `bit < 8` check above
+                #[allow(clippy::arithmetic_side_effects)]
+                {
+                    bit += 1;
+                }
+            }
+
+            // Won't overflow due to `i < bytes.len()` check above
+            #[allow(clippy::arithmetic_side_effects)]
+            {
+                i += 1;
+            }
+        }
+
+        !crc
+    }
+
+    /// Compute a zero-padded key of `K` bytes for this OID, e.g. for indexing OIDs in an
+    /// external key-value store which requires fixed-length keys.
+    ///
+    /// Each arc is encoded as a length byte followed by its minimal big endian representation,
+    /// which uses at most 5 bytes per arc. Provided the encodings of both OIDs fit in `K` bytes,
+    /// comparing keys bytewise gives the same order as comparing the arcs of the OIDs in turn,
+    /// with an OID sorting before any OID it is a prefix of. Note this is not the order given by
+    /// the [`Ord`] impl on [`ObjectIdentifier`], which compares the BER/DER serializations.
+    ///
+    /// Encodings longer than `K` bytes are truncated, in which case the order is only partially
+    /// preserved: when one OID sorts before another its key will not sort after the other's key,
+    /// but distinct OIDs may share a key.
+    pub fn sort_key<const K: usize>(&self) -> [u8; K] {
+        let bytes = self.arcs().flat_map(|arc| {
+            let be_bytes = arc.to_be_bytes();
+
+            // Zero is encoded as a single byte
+            let len = be_bytes
+                .len()
+                .saturating_sub(arc.leading_zeros() as usize / 8)
+                .max(1);
+
+            iter::once(len as u8).chain(
+                be_bytes
+                    .into_iter()
+                    .skip(be_bytes.len().saturating_sub(len)),
+            )
+        });
+
+        let mut key = [0u8; K];
+
+        for (slot, byte) in key.iter_mut().zip(bytes) {
+            *slot = byte;
+        }
+
+        key
+    }
+
+    /// Require that no arc of this OID exceeds `max_arc`, e.g. for profiles which encode arcs
+    /// into fixed-size fields.
+    ///
+    /// Returns [`Error::ArcOutOfBounds`] identifying the first arc which exceeds the maximum.
+    pub fn validate_arc_bounds(&self, max_arc: Arc) -> Result<()> {
+        match self.arcs().enumerate().find(|&(_, arc)| arc > max_arc) {
+            Some((index, arc)) => Err(Error::ArcOutOfBounds { index, arc }),
+            None => Ok(()),
+        }
+    }
+
+    /// Does this OID contain the given arc at any position?
+    pub fn contains_arc(&self, arc: Arc) -> bool {
+        self.arcs().any(|a| a == arc)
+    }
 }
 
 impl AsRef<[u8]> for ObjectIdentifierRef {
+    #[inline]
     fn as_ref(&self) -> &[u8] {
         self.as_bytes()
     }
@@ -364,23 +1445,66 @@ impl<'a> TryFrom<&'a [u8]> for &'a ObjectIdentifierRef {
     }
 }
 
+/// The alternate form (i.e. `{:#?}`) additionally shows the arcs and the hex-encoded value bytes.
 impl fmt::Debug for ObjectIdentifierRef {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "ObjectIdentifierRef({})", self)
+        if f.alternate() {
+            debug_alternate("ObjectIdentifierRef", self, f)
+        } else {
+            write!(f, "ObjectIdentifierRef({})", self)
+        }
     }
 }
 
+/// Write the alternate `Debug` form of an OID as a struct with the given name.
+fn debug_alternate(
+    name: &str,
+    oid: &ObjectIdentifierRef,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    /// List of arcs.
+    struct ArcList<'a>(&'a ObjectIdentifierRef);
+
+    impl fmt::Debug for ArcList<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_list().entries(self.0.arcs()).finish()
+        }
+    }
+
+    /// Uppercase hex bytes.
+    struct Hex<'a>(&'a [u8]);
+
+    impl fmt::Debug for Hex<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            for byte in self.0 {
+                write!(f, "{:02X}", byte)?;
+            }
+
+            Ok(())
+        }
+    }
+
+    f.debug_struct(name)
+        .field("oid", &format_args!("{}", oid))
+        .field("arcs", &ArcList(oid))
+        .field("bytes", &Hex(oid.as_bytes()))
+        .finish()
+}
+
+/// Renders the dot-delimited string form of the OID.
+///
+/// The alternate form (i.e. `{:#}`) annotates each arc with its index, e.g. `1[0].2[1].840[2]`.
 impl fmt::Display for ObjectIdentifierRef {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let len = self.arcs().count();
-
-        for (i, arc) in self.arcs().enumerate() {
-            write!(f, "{}", arc)?;
+        for (i, arc, is_last) in self.arcs_enumerated() {
+            if f.alternate() {
+                write!(f, "{}[{}]", arc, i)?;
+            } else {
+                write!(f, "{}", arc)?;
+            }
 
-            if let Some(j) = i.checked_add(1) {
-                if j < len {
-                    write!(f, ".")?;
-                }
+            if !is_last {
+                write!(f, ".")?;
             }
         }
 
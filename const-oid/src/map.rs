@@ -0,0 +1,126 @@
+//! Fixed-capacity map keyed by OID.
+
+use crate::{Buffer, ObjectIdentifier, DEFAULT_MAX_SIZE};
+use core::fmt;
+
+/// Placeholder for unoccupied key slots. Never exposed to callers.
+const VACANT: ObjectIdentifier = ObjectIdentifier {
+    ber: Buffer {
+        length: 0,
+        bytes: [0u8; DEFAULT_MAX_SIZE],
+    },
+};
+
+/// Heapless map from [`ObjectIdentifier`] keys to values of type `V`, with a fixed capacity of
+/// `CAP` entries.
+///
+/// Keys are kept sorted by their BER/DER serialization, and lookups are performed using binary
+/// search. It is suitable for use in `no_std` environments, e.g. as a dispatch table.
+#[derive(Clone)]
+pub struct OidMap<V, const CAP: usize> {
+    /// Keys sorted by BER/DER serialization. Only the first `len` are occupied.
+    keys: [ObjectIdentifier; CAP],
+
+    /// Values corresponding to `keys`. Only the first `len` are occupied.
+    values: [Option<V>; CAP],
+
+    /// Number of entries in the map.
+    len: usize,
+}
+
+impl<V, const CAP: usize> OidMap<V, CAP> {
+    /// Vacant value slot.
+    const NONE: Option<V> = None;
+
+    /// Create a new empty map.
+    pub const fn new() -> Self {
+        Self {
+            keys: [VACANT; CAP],
+            values: [Self::NONE; CAP],
+            len: 0,
+        }
+    }
+
+    /// Get the number of entries in the map.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Is this map empty?
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the maximum number of entries this map can hold.
+    pub const fn capacity(&self) -> usize {
+        CAP
+    }
+
+    /// Insert a value into the map, returning the previous value for this key (if any).
+    ///
+    /// If the map is full and the key isn't already present, the key and value are handed back
+    /// as an error.
+    pub fn insert(
+        &mut self,
+        key: ObjectIdentifier,
+        value: V,
+    ) -> Result<Option<V>, (ObjectIdentifier, V)> {
+        match self.search(&key) {
+            Ok(index) => Ok(self.values[index].replace(value)),
+            Err(_) if self.len == CAP => Err((key, value)),
+            Err(index) => {
+                // `len < CAP` is ensured by the guard on the previous arm
+                let end = self.len;
+                self.keys[index..=end].rotate_right(1);
+                self.values[index..=end].rotate_right(1);
+                self.keys[index] = key;
+                self.values[index] = Some(value);
+                self.len = end.saturating_add(1);
+                Ok(None)
+            }
+        }
+    }
+
+    /// Get the value associated with the given key.
+    pub fn get(&self, key: &ObjectIdentifier) -> Option<&V> {
+        let index = self.search(key).ok()?;
+        self.values[index].as_ref()
+    }
+
+    /// Get a mutable reference to the value associated with the given key.
+    pub fn get_mut(&mut self, key: &ObjectIdentifier) -> Option<&mut V> {
+        let index = self.search(key).ok()?;
+        self.values[index].as_mut()
+    }
+
+    /// Does the map contain the given key?
+    pub fn contains_key(&self, key: &ObjectIdentifier) -> bool {
+        self.search(key).is_ok()
+    }
+
+    /// Iterate over the entries of the map, sorted by the BER/DER serialization of the keys.
+    pub fn iter(&self) -> impl Iterator<Item = (&ObjectIdentifier, &V)> {
+        self.keys[..self.len]
+            .iter()
+            .zip(&self.values[..self.len])
+            .filter_map(|(key, value)| value.as_ref().map(|value| (key, value)))
+    }
+
+    /// Binary search for the given key's position.
+    fn search(&self, key: &ObjectIdentifier) -> Result<usize, usize> {
+        self.keys[..self.len].binary_search_by(|probe| probe.as_bytes().cmp(key.as_bytes()))
+    }
+}
+
+// Only the occupied entries are shown: the vacant key slots are not valid OIDs.
+impl<V: fmt::Debug, const CAP: usize> fmt::Debug for OidMap<V, CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<V, const CAP: usize> Default for OidMap<V, CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -29,6 +29,7 @@ impl Parser {
                 encoder: Encoder::new(),
             }
             .parse_bytes(bytes),
+            b'.' => Err(Error::LeadingDot),
             actual => Err(Error::DigitExpected { actual }),
         }
     }
@@ -39,53 +40,147 @@ impl Parser {
     }
 
     /// Parse the remaining bytes
-    const fn parse_bytes(mut self, bytes: &[u8]) -> Result<Self> {
-        match bytes {
-            // TODO(tarcieri): use `?` when stable in `const fn`
-            [] => match self.encoder.arc(self.current_arc) {
-                Ok(encoder) => {
-                    self.encoder = encoder;
-                    Ok(self)
+    ///
+    /// This is a loop rather than recursing on each byte so the stack usage is constant for
+    /// arbitrarily long inputs.
+    const fn parse_bytes(mut self, mut bytes: &[u8]) -> Result<Self> {
+        // Has a digit been seen since the last `.`?
+        let mut has_digits = false;
+
+        loop {
+            match bytes {
+                // TODO(tarcieri): use `?` when stable in `const fn`
+                [] => {
+                    return match self.encoder.arc(self.current_arc) {
+                        Ok(encoder) => {
+                            self.encoder = encoder;
+                            Ok(self)
+                        }
+                        Err(err) => Err(err),
+                    }
                 }
-                Err(err) => Err(err),
-            },
-            // TODO(tarcieri): checked arithmetic
-            #[allow(clippy::arithmetic_side_effects)]
-            [byte @ b'0'..=b'9', remaining @ ..] => {
-                let digit = byte.saturating_sub(b'0');
-                self.current_arc = self.current_arc * 10 + digit as Arc;
-                self.parse_bytes(remaining)
-            }
-            [b'.', remaining @ ..] => {
-                if remaining.is_empty() {
-                    return Err(Error::TrailingDot);
+                [byte @ b'0'..=b'9', remaining @ ..] => {
+                    let digit = byte.saturating_sub(b'0');
+
+                    self.current_arc = match self.current_arc.checked_mul(10) {
+                        Some(arc) => match arc.checked_add(digit as Arc) {
+                            Some(arc) => arc,
+                            None => return Err(Error::ArcTooBig),
+                        },
+                        None => return Err(Error::ArcTooBig),
+                    };
+
+                    has_digits = true;
+                    bytes = remaining;
                 }
+                [b'.', remaining @ ..] => {
+                    if remaining.is_empty() {
+                        return Err(Error::TrailingDot);
+                    }
 
-                // TODO(tarcieri): use `?` when stable in `const fn`
-                match self.encoder.arc(self.current_arc) {
-                    Ok(encoder) => {
-                        self.encoder = encoder;
-                        self.current_arc = 0;
-                        self.parse_bytes(remaining)
+                    // Empty arc, e.g. `1..2`
+                    if !has_digits {
+                        return Err(Error::DigitExpected { actual: b'.' });
+                    }
+
+                    // TODO(tarcieri): use `?` when stable in `const fn`
+                    match self.encoder.arc(self.current_arc) {
+                        Ok(encoder) => {
+                            self.encoder = encoder;
+                            self.current_arc = 0;
+                            has_digits = false;
+                            bytes = remaining;
+                        }
+                        Err(err) => return Err(err),
                     }
-                    Err(err) => Err(err),
                 }
+                [byte, ..] => return Err(Error::DigitExpected { actual: *byte }),
             }
-            [byte, ..] => Err(Error::DigitExpected { actual: *byte }),
         }
     }
 }
 
+/// Parse the dot-delimited decimal arcs in `s` at runtime, encoding them with the given encoder.
+pub(crate) fn parse_arcs<const MAX_SIZE: usize>(
+    mut encoder: Encoder<MAX_SIZE>,
+    s: &str,
+    parse_arc: fn(&str) -> Result<Arc>,
+) -> Result<Encoder<MAX_SIZE>> {
+    if s.is_empty() {
+        return Err(Error::Empty);
+    }
+
+    if s.starts_with('.') {
+        return Err(Error::LeadingDot);
+    }
+
+    if s.ends_with('.') {
+        return Err(Error::TrailingDot);
+    }
+
+    for arc in s.split('.') {
+        encoder = encoder.arc(parse_arc(arc)?)?;
+    }
+
+    Ok(encoder)
+}
+
+/// Parse a single decimal arc at runtime, with checked arithmetic.
+pub(crate) fn parse_arc(s: &str) -> Result<Arc> {
+    parse_digits(s, false)
+}
+
+/// Parse a single decimal arc at runtime, ignoring `_` digit separators.
+///
+/// Separators must appear between two digits, i.e. leading, trailing, or doubled underscores are
+/// rejected.
+pub(crate) fn parse_arc_lenient(s: &str) -> Result<Arc> {
+    let bytes = s.as_bytes();
+
+    if bytes.first() == Some(&b'_')
+        || bytes.last() == Some(&b'_')
+        || bytes.windows(2).any(|pair| pair == b"__")
+    {
+        return Err(Error::DigitExpected { actual: b'_' });
+    }
+
+    parse_digits(s, true)
+}
+
+/// Parse decimal digits, optionally skipping `_` separators.
+fn parse_digits(s: &str, separators: bool) -> Result<Arc> {
+    let mut arc: Arc = 0;
+
+    if s.is_empty() {
+        return Err(Error::DigitExpected { actual: b'.' });
+    }
+
+    for &byte in s.as_bytes() {
+        let digit = match byte {
+            b'0'..=b'9' => byte.saturating_sub(b'0'),
+            b'_' if separators => continue,
+            actual => return Err(Error::DigitExpected { actual }),
+        };
+
+        arc = arc
+            .checked_mul(10)
+            .and_then(|arc| arc.checked_add(digit as Arc))
+            .ok_or(Error::ArcTooBig)?;
+    }
+
+    Ok(arc)
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::Parser;
-    use crate::Error;
+    use crate::{Error, ObjectIdentifier};
 
     #[test]
     fn parse() {
         let oid = Parser::parse("1.23.456").unwrap().finish().unwrap();
-        assert_eq!(oid, "1.23.456".parse().unwrap());
+        assert_eq!(oid, "1.23.456".parse::<ObjectIdentifier>().unwrap());
     }
 
     #[test]
@@ -106,8 +201,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn reject_overflowing_arc() {
+        assert_eq!(
+            Parser::parse("1.2.4294967296").err().unwrap(),
+            Error::ArcTooBig
+        );
+        assert_eq!(
+            Parser::parse("1.2.99999999999999999999").err().unwrap(),
+            Error::ArcTooBig
+        );
+        assert_eq!(
+            Parser::parse("1.2.4294967295").unwrap().finish().unwrap(),
+            "1.2.4294967295".parse::<ObjectIdentifier>().unwrap()
+        );
+    }
+
     #[test]
     fn reject_trailing_dot() {
         assert_eq!(Parser::parse("1.23.").err().unwrap(), Error::TrailingDot);
     }
+
+    #[test]
+    fn reject_leading_dot() {
+        assert_eq!(Parser::parse(".").err().unwrap(), Error::LeadingDot);
+        assert_eq!(Parser::parse(".1.2").err().unwrap(), Error::LeadingDot);
+    }
+
+    #[test]
+    fn reject_empty_arc() {
+        for s in ["1..2", "1.2..3", "1.2.3..4"] {
+            assert_eq!(
+                Parser::parse(s).err().unwrap(),
+                Error::DigitExpected { actual: b'.' }
+            );
+        }
+    }
 }
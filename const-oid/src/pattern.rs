@@ -0,0 +1,98 @@
+//! OID patterns with wildcards.
+
+use crate::{parser::parse_arc, Arc, Error, ObjectIdentifierRef, Result, DEFAULT_MAX_SIZE};
+use core::str::FromStr;
+
+/// Maximum number of segments in a pattern.
+///
+/// Each arc after the first two occupies at least one byte, so this is the
+/// maximum number of arcs in an OID of the default size.
+const MAX_SEGMENTS: usize = DEFAULT_MAX_SIZE + 1;
+
+/// Pattern segment.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Segment {
+    /// Matches the given arc.
+    Arc(Arc),
+
+    /// Matches any single arc (`*`).
+    Any,
+}
+
+/// Pattern for matching OIDs by shape, e.g. `1.3.6.1.4.1.*.1`.
+///
+/// Patterns are dot-delimited like OIDs, and each segment is either:
+///
+/// - an arc, which matches that exact arc
+/// - `*`, which matches any single arc
+/// - `**`, which is only allowed as the final segment, and matches any number
+///   (including zero) of remaining arcs
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct OidPattern {
+    /// Segments of the pattern. Only the first `len` are used.
+    segments: [Segment; MAX_SEGMENTS],
+
+    /// Number of segments.
+    len: usize,
+
+    /// Does the pattern end with `**`?
+    trailing: bool,
+}
+
+impl OidPattern {
+    /// Parse a pattern from its dot-delimited string form.
+    pub fn new(s: &str) -> Result<Self> {
+        if s.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        let mut pattern = Self {
+            segments: [Segment::Any; MAX_SEGMENTS],
+            len: 0,
+            trailing: false,
+        };
+
+        let mut segments = s.split('.').peekable();
+
+        while let Some(segment) = segments.next() {
+            let segment = match segment {
+                "" if segments.peek().is_none() => return Err(Error::TrailingDot),
+                "**" if segments.peek().is_none() => {
+                    pattern.trailing = true;
+                    break;
+                }
+                "*" => Segment::Any,
+                _ => Segment::Arc(parse_arc(segment)?),
+            };
+
+            *pattern.segments.get_mut(pattern.len).ok_or(Error::Length)? = segment;
+
+            pattern.len = pattern.len.saturating_add(1);
+        }
+
+        Ok(pattern)
+    }
+
+    /// Does the given OID match this pattern?
+    pub fn matches(&self, oid: &ObjectIdentifierRef) -> bool {
+        let mut arcs = oid.arcs();
+
+        for segment in &self.segments[..self.len] {
+            match (segment, arcs.next()) {
+                (Segment::Any, Some(_)) => (),
+                (Segment::Arc(expected), Some(arc)) if *expected == arc => (),
+                _ => return false,
+            }
+        }
+
+        self.trailing || arcs.next().is_none()
+    }
+}
+
+impl FromStr for OidPattern {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::new(s)
+    }
+}
@@ -0,0 +1,261 @@
+//! Parse diagnostics for the dot-delimited string form of OIDs.
+
+use crate::{encoder::Encoder, Arc, Error, ObjectIdentifier};
+use core::{fmt, ops::Range};
+
+/// Detailed report describing why an OID string failed to parse.
+///
+/// Produced by [`ObjectIdentifier::parse_with_report`], and intended for tooling which displays
+/// errors inline with the offending input.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseReport {
+    /// Underlying error, i.e. the same error [`ObjectIdentifier::new`] returns for this input.
+    pub error: Error,
+
+    /// Byte range of the offending portion of the input.
+    pub span: Range<usize>,
+
+    /// Human-readable description of the problem.
+    pub message: &'static str,
+
+    /// Character which was found at the start of `span`, if any.
+    pub found: Option<char>,
+
+    /// Description of what was expected instead, if applicable.
+    pub expected: Option<&'static str>,
+}
+
+impl ParseReport {
+    /// Create a new report with no `found` or `expected` values.
+    fn new(error: Error, span: Range<usize>, message: &'static str) -> Self {
+        Self {
+            error,
+            span,
+            message,
+            found: None,
+            expected: None,
+        }
+    }
+
+    /// Set the `found` and `expected` values of this report.
+    fn found(mut self, found: Option<char>, expected: &'static str) -> Self {
+        self.found = found;
+        self.expected = Some(expected);
+        self
+    }
+}
+
+impl fmt::Display for ParseReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at {}..{}",
+            self.message, self.span.start, self.span.end
+        )?;
+
+        match (self.found, self.expected) {
+            (Some(found), Some(expected)) => {
+                write!(f, ": expected {}, found '{}'", expected, found)
+            }
+            (None, Some(expected)) => write!(f, ": expected {}", expected),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl From<ParseReport> for Error {
+    fn from(report: ParseReport) -> Error {
+        report.error
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseReport {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Parse an OID from a dot-delimited string, producing a [`ParseReport`] on failure.
+pub(crate) fn parse(s: &str) -> Result<ObjectIdentifier, ParseReport> {
+    if s.is_empty() {
+        return Err(ParseReport::new(Error::Empty, 0..0, "OID value is empty").found(None, "digit"));
+    }
+
+    let mut encoder = Encoder::new();
+    let mut start = 0usize;
+
+    for (index, segment) in s.split('.').enumerate() {
+        let end = start.saturating_add(segment.len());
+
+        if segment.is_empty() {
+            return Err(if index == 0 {
+                ParseReport::new(Error::LeadingDot, 0..1, "OID starts with leading '.'")
+                    .found(Some('.'), "digit")
+            } else if end == s.len() || end.saturating_add(1) == s.len() {
+                // Like the parser, a trailing `.` takes precedence over an empty arc before it,
+                // e.g. `1..`
+                let span = s.len().saturating_sub(1)..s.len();
+                ParseReport::new(Error::TrailingDot, span, "OID ends with trailing '.'")
+                    .found(Some('.'), "digit")
+            } else {
+                let span = start..start.saturating_add(1);
+                ParseReport::new(Error::DigitExpected { actual: b'.' }, span, "arc is empty")
+                    .found(Some('.'), "digit")
+            });
+        }
+
+        let mut arc: Arc = 0;
+
+        for (offset, c) in segment.char_indices() {
+            let digit = match c.to_digit(10) {
+                Some(digit) => digit,
+                None => {
+                    let pos = start.saturating_add(offset);
+                    let error = Error::DigitExpected {
+                        actual: s.as_bytes()[pos],
+                    };
+                    let span = pos..pos.saturating_add(c.len_utf8());
+                    return Err(ParseReport::new(error, span, "arc contains non-digit")
+                        .found(Some(c), "digit"));
+                }
+            };
+
+            arc = match arc.checked_mul(10).and_then(|arc| arc.checked_add(digit)) {
+                Some(arc) => arc,
+                None => {
+                    return Err(ParseReport::new(
+                        Error::ArcTooBig,
+                        start..end,
+                        "arc is larger than 32-bits",
+                    ))
+                }
+            };
+        }
+
+        encoder = encoder.arc(arc).map_err(|error| {
+            let report = ParseReport::new(error, start..end, "arc is invalid");
+
+            match (error, index) {
+                (Error::ArcInvalid { .. }, 0) => ParseReport {
+                    message: "first arc is out of range",
+                    ..report
+                }
+                .found(None, "0, 1, or 2"),
+                (Error::ArcInvalid { .. }, _) => ParseReport {
+                    message: "second arc is out of range",
+                    ..report
+                }
+                .found(None, "value in the range 0-39 (unless the first arc is 2)"),
+                (Error::Length, _) => ParseReport {
+                    message: "OID exceeds maximum encoded length",
+                    ..report
+                },
+                _ => report,
+            }
+        })?;
+
+        start = end.saturating_add(1);
+    }
+
+    encoder
+        .finish()
+        .map_err(|error| ParseReport::new(error, 0..s.len(), "OID has too few arcs"))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{parse, ParseReport};
+    use crate::{Error, ObjectIdentifier};
+
+    #[test]
+    fn parse_valid() {
+        assert_eq!(
+            parse("1.2.840.113549"),
+            Ok(ObjectIdentifier::new_unwrap("1.2.840.113549"))
+        );
+    }
+
+    #[test]
+    fn report_empty() {
+        let report = parse("").unwrap_err();
+        assert_eq!(report.error, Error::Empty);
+        assert_eq!(report.span, 0..0);
+    }
+
+    #[test]
+    fn report_non_digit() {
+        let report = parse("1.2.8x0").unwrap_err();
+        assert_eq!(
+            report,
+            ParseReport {
+                error: Error::DigitExpected { actual: b'x' },
+                span: 5..6,
+                message: "arc contains non-digit",
+                found: Some('x'),
+                expected: Some("digit"),
+            }
+        );
+    }
+
+    #[test]
+    fn report_empty_arc() {
+        let report = parse("1.2..3").unwrap_err();
+        assert_eq!(report.error, Error::DigitExpected { actual: b'.' });
+        assert_eq!(report.span, 4..5);
+        assert_eq!(report.message, "arc is empty");
+    }
+
+    #[test]
+    fn report_leading_dot() {
+        let report = parse(".1.2").unwrap_err();
+        assert_eq!(report.error, Error::LeadingDot);
+        assert_eq!(report.span, 0..1);
+    }
+
+    #[test]
+    fn report_trailing_dot() {
+        let report = parse("1.2.").unwrap_err();
+        assert_eq!(report.error, Error::TrailingDot);
+        assert_eq!(report.span, 3..4);
+
+        // Preceded by an empty arc
+        let report = parse("1.2..").unwrap_err();
+        assert_eq!(report.error, Error::TrailingDot);
+        assert_eq!(report.span, 4..5);
+    }
+
+    #[test]
+    fn report_arc_out_of_range() {
+        let report = parse("3.2.1").unwrap_err();
+        assert_eq!(report.error, Error::ArcInvalid { arc: 3 });
+        assert_eq!(report.span, 0..1);
+        assert_eq!(report.message, "first arc is out of range");
+
+        let report = parse("1.400.1").unwrap_err();
+        assert_eq!(report.error, Error::ArcInvalid { arc: 400 });
+        assert_eq!(report.span, 2..5);
+        assert_eq!(report.message, "second arc is out of range");
+    }
+
+    #[test]
+    fn report_arc_too_big() {
+        let report = parse("1.2.4294967296").unwrap_err();
+        assert_eq!(report.error, Error::ArcTooBig);
+        assert_eq!(report.span, 4..14);
+    }
+
+    #[test]
+    fn report_matches_new() {
+        for s in [
+            "", ".", "1", "1.", ".1", "1..2", "1.2.", "1.2.x", ".1.2", "3.1.2", "1.40.1", "1..",
+            "1.2..", "1.2.3..", "1...",
+        ] {
+            assert_eq!(
+                parse(s).unwrap_err().error,
+                ObjectIdentifier::new(s).unwrap_err()
+            );
+        }
+    }
+}
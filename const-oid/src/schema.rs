@@ -0,0 +1,137 @@
+//! Arc-by-arc OID schemas, e.g. for enforcing allocation policies.
+
+use crate::{Arc, ObjectIdentifierRef};
+use core::fmt;
+
+/// Constraint on the arc at a single position of an OID.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ArcConstraint<'a> {
+    /// Any arc is allowed.
+    Any,
+
+    /// Arcs within the given inclusive range are allowed.
+    Range {
+        /// Smallest allowed arc.
+        min: Arc,
+
+        /// Largest allowed arc.
+        max: Arc,
+    },
+
+    /// Only the given arcs are allowed.
+    Set(&'a [Arc]),
+}
+
+impl ArcConstraint<'_> {
+    /// Is the given arc allowed by this constraint?
+    pub fn allows(&self, arc: Arc) -> bool {
+        match self {
+            ArcConstraint::Any => true,
+            ArcConstraint::Range { min, max } => (*min..=*max).contains(&arc),
+            ArcConstraint::Set(arcs) => arcs.contains(&arc),
+        }
+    }
+}
+
+/// Schema which constrains the arcs of an OID position by position.
+///
+/// ```
+/// use const_oid::{ArcConstraint, ObjectIdentifier, OidSchema, SchemaError};
+///
+/// // Third arc must be 840 or 999, fourth within 1-100
+/// const SCHEMA: OidSchema<'static> = OidSchema::new(&[
+///     ArcConstraint::Set(&[1]),
+///     ArcConstraint::Set(&[2]),
+///     ArcConstraint::Set(&[840, 999]),
+///     ArcConstraint::Range { min: 1, max: 100 },
+/// ]);
+///
+/// assert_eq!(SCHEMA.validate(&ObjectIdentifier::new_unwrap("1.2.840.42")), Ok(()));
+/// assert_eq!(
+///     SCHEMA.validate(&ObjectIdentifier::new_unwrap("1.2.841.42")),
+///     Err(SchemaError::ArcRejected { index: 2, arc: 841 })
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct OidSchema<'a> {
+    /// Constraints for each position of the OID.
+    constraints: &'a [ArcConstraint<'a>],
+
+    /// Are arcs beyond the constrained positions allowed?
+    extra_arcs: bool,
+}
+
+impl<'a> OidSchema<'a> {
+    /// Create a schema from per-position constraints.
+    ///
+    /// By default OIDs must have exactly as many arcs as there are constraints.
+    pub const fn new(constraints: &'a [ArcConstraint<'a>]) -> Self {
+        Self {
+            constraints,
+            extra_arcs: false,
+        }
+    }
+
+    /// Allow any number of unconstrained arcs after the constrained positions.
+    pub const fn allow_extra_arcs(mut self) -> Self {
+        self.extra_arcs = true;
+        self
+    }
+
+    /// Check that the given OID conforms to this schema.
+    pub fn validate(&self, oid: &ObjectIdentifierRef) -> Result<(), SchemaError> {
+        let mut arcs = oid.arcs();
+
+        for (index, constraint) in self.constraints.iter().enumerate() {
+            match arcs.next() {
+                Some(arc) if constraint.allows(arc) => (),
+                Some(arc) => return Err(SchemaError::ArcRejected { index, arc }),
+                None => return Err(SchemaError::TooFewArcs),
+            }
+        }
+
+        if !self.extra_arcs && arcs.next().is_some() {
+            return Err(SchemaError::TooManyArcs);
+        }
+
+        Ok(())
+    }
+}
+
+/// Reasons an OID does not conform to an [`OidSchema`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SchemaError {
+    /// Arc is not allowed by the constraint at its position.
+    ArcRejected {
+        /// Position of the arc within the OID.
+        index: usize,
+
+        /// Arc which was rejected.
+        arc: Arc,
+    },
+
+    /// OID has fewer arcs than the schema has constraints.
+    TooFewArcs,
+
+    /// OID has more arcs than the schema has constraints.
+    TooManyArcs,
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            SchemaError::ArcRejected { index, arc } => {
+                write!(
+                    f,
+                    "OID arc {} at position {} not allowed by schema",
+                    arc, index
+                )
+            }
+            SchemaError::TooFewArcs => f.write_str("OID has fewer arcs than schema"),
+            SchemaError::TooManyArcs => f.write_str("OID has more arcs than schema"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SchemaError {}
@@ -0,0 +1,87 @@
+//! Sets of OIDs which can match entire subtrees, e.g. for policy evaluation.
+
+use crate::ObjectIdentifier;
+use alloc::collections::{btree_set, BTreeSet};
+
+/// Set of OIDs which can be queried either for an exact match or for whether an OID falls within
+/// the subtree of any member.
+///
+/// Inserting `1.2.3` models "the subtree rooted at `1.2.3` is permitted": it is matched exactly
+/// by [`OidSet::contains_exact`], and `1.2.3.4` is additionally matched by
+/// [`OidSet::contains_or_ancestor`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct OidSet {
+    /// Members of the set.
+    oids: BTreeSet<ObjectIdentifier>,
+}
+
+impl OidSet {
+    /// Create an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an OID to the set.
+    ///
+    /// Returns `false` if the OID was already a member.
+    pub fn insert(&mut self, oid: ObjectIdentifier) -> bool {
+        self.oids.insert(oid)
+    }
+
+    /// Remove an OID from the set.
+    ///
+    /// Returns `false` if the OID was not a member. Descendants of the OID which were inserted
+    /// separately remain members.
+    pub fn remove(&mut self, oid: &ObjectIdentifier) -> bool {
+        self.oids.remove(oid)
+    }
+
+    /// Is the given OID itself a member of the set?
+    pub fn contains_exact(&self, oid: &ObjectIdentifier) -> bool {
+        self.oids.contains(oid)
+    }
+
+    /// Is the given OID, or any of its ancestors, a member of the set?
+    pub fn contains_or_ancestor(&self, oid: &ObjectIdentifier) -> bool {
+        let mut current = Some(*oid);
+
+        while let Some(oid) = current {
+            if self.oids.contains(&oid) {
+                return true;
+            }
+
+            current = oid.parent();
+        }
+
+        false
+    }
+
+    /// Get the number of OIDs in the set.
+    pub fn len(&self) -> usize {
+        self.oids.len()
+    }
+
+    /// Is the set empty?
+    pub fn is_empty(&self) -> bool {
+        self.oids.is_empty()
+    }
+
+    /// Iterate over the members of the set.
+    pub fn iter(&self) -> btree_set::Iter<'_, ObjectIdentifier> {
+        self.oids.iter()
+    }
+}
+
+impl Extend<ObjectIdentifier> for OidSet {
+    fn extend<T: IntoIterator<Item = ObjectIdentifier>>(&mut self, iter: T) {
+        self.oids.extend(iter);
+    }
+}
+
+impl FromIterator<ObjectIdentifier> for OidSet {
+    fn from_iter<T: IntoIterator<Item = ObjectIdentifier>>(iter: T) -> Self {
+        Self {
+            oids: iter.into_iter().collect(),
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! Minimal ASN.1 DER tag-length-value support.
+
+use crate::{Error, ObjectIdentifier, Result};
+
+/// ASN.1 tag for `OBJECT IDENTIFIER`.
+pub(crate) const OBJECT_IDENTIFIER_TAG: u8 = 0x06;
+
+/// ASN.1 tag for a constructed `SEQUENCE`.
+pub(crate) const SEQUENCE_TAG: u8 = 0x30;
+
+/// Compute the total length of a DER TLV (tag + length + value) with the
+/// given value length.
+// Can't overflow as `value_len` is bounded by the `u8` length of `Buffer`
+#[allow(clippy::arithmetic_side_effects)]
+pub(crate) const fn encoded_len(value_len: usize) -> usize {
+    let length_len = match value_len {
+        0..=0x7F => 1,
+        0x80..=0xFF => 2,
+        _ => 3,
+    };
+
+    1 + length_len + value_len
+}
+
+/// Encode a DER tag and length header for a value of the given length.
+///
+/// Returns a buffer containing the header along with its length.
+#[cfg(feature = "alloc")]
+pub(crate) const fn encode_header(tag: u8, value_len: u8) -> ([u8; 3], usize) {
+    match value_len {
+        0..=0x7F => ([tag, value_len, 0], 2),
+        _ => ([tag, 0x81, value_len], 3),
+    }
+}
+
+/// Decode a DER TLV with the given tag from the front of `bytes`.
+///
+/// Returns the value bytes along with any remaining bytes following the
+/// element.
+pub(crate) fn decode(tag: u8, bytes: &[u8]) -> Result<(&[u8], &[u8])> {
+    let (&actual, bytes) = bytes.split_first().ok_or(Error::Empty)?;
+
+    if actual != tag {
+        return Err(Error::TagInvalid { actual });
+    }
+
+    let (&first, mut bytes) = bytes.split_first().ok_or(Error::Length)?;
+
+    let len = match first {
+        0..=0x7F => first as usize,
+        0x81..=0x84 => {
+            let nbytes = (first & 0x7F) as usize;
+
+            if bytes.len() < nbytes {
+                return Err(Error::Length);
+            }
+
+            let (len_bytes, rest) = bytes.split_at(nbytes);
+            bytes = rest;
+
+            // DER requires the minimal number of length octets
+            if len_bytes[0] == 0 {
+                return Err(Error::Length);
+            }
+
+            let len = len_bytes
+                .iter()
+                .fold(0usize, |acc, &byte| acc << 8 | byte as usize);
+
+            if len < 0x80 {
+                return Err(Error::Length);
+            }
+
+            len
+        }
+        _ => return Err(Error::Length),
+    };
+
+    if bytes.len() < len {
+        return Err(Error::Length);
+    }
+
+    Ok(bytes.split_at(len))
+}
+
+/// [`winnow`] parser for a DER-encoded `OBJECT IDENTIFIER` element.
+///
+/// Consumes the tag, length, and value of a single element from the front of
+/// `input`, leaving any remaining bytes for subsequent parsers.
+///
+/// Failures are reported as backtracking errors carrying the underlying
+/// [`Error`], so the parser composes with combinators like `alt` and `opt`.
+#[cfg(feature = "winnow")]
+pub fn parse_der<'i, E>(input: &mut &'i [u8]) -> winnow::PResult<ObjectIdentifier, E>
+where
+    E: winnow::error::ParserError<&'i [u8]> + winnow::error::FromExternalError<&'i [u8], Error>,
+{
+    match ObjectIdentifier::from_der_prefix(input) {
+        Ok((oid, rest)) => {
+            *input = rest;
+            Ok(oid)
+        }
+        Err(err) => Err(winnow::error::ErrMode::Backtrack(E::from_external_error(
+            input,
+            winnow::error::ErrorKind::Verify,
+            err,
+        ))),
+    }
+}
+
+/// [`Iterator`] over the elements of a DER-encoded `SEQUENCE OF OBJECT IDENTIFIER`.
+///
+/// Iteration stops after the first malformed element.
+#[derive(Clone, Debug)]
+pub struct SequenceOf<'a> {
+    /// Remaining DER-encoded elements of the sequence.
+    remaining: &'a [u8],
+}
+
+impl<'a> SequenceOf<'a> {
+    /// Parse the outer `SEQUENCE` header, returning an iterator over its elements.
+    pub(crate) fn new(der: &'a [u8]) -> Result<Self> {
+        let (remaining, trailing) = decode(SEQUENCE_TAG, der)?;
+
+        if !trailing.is_empty() {
+            return Err(Error::TrailingData);
+        }
+
+        Ok(Self { remaining })
+    }
+}
+
+impl<'a> Iterator for SequenceOf<'a> {
+    type Item = Result<ObjectIdentifier>;
+
+    fn next(&mut self) -> Option<Result<ObjectIdentifier>> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        let result = decode(OBJECT_IDENTIFIER_TAG, self.remaining).and_then(|(value, rest)| {
+            self.remaining = rest;
+            ObjectIdentifier::from_bytes(value)
+        });
+
+        if result.is_err() {
+            self.remaining = &[];
+        }
+
+        Some(result)
+    }
+}
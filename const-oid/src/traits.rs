@@ -8,6 +8,27 @@ pub trait AssociatedOid {
     const OID: ObjectIdentifier;
 }
 
+/// Get the OID associated with the type `T`, i.e. [`AssociatedOid::OID`].
+///
+/// Unlike the associated constant, this can be passed as a function pointer.
+///
+/// ```
+/// use const_oid::{oid_of, AssociatedOid, ObjectIdentifier};
+///
+/// struct EcPublicKey;
+///
+/// impl AssociatedOid for EcPublicKey {
+///     const OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+/// }
+///
+/// let get_oid: fn() -> ObjectIdentifier = oid_of::<EcPublicKey>;
+/// assert_eq!(get_oid(), EcPublicKey::OID);
+/// assert_eq!(oid_of::<EcPublicKey>().to_string(), "1.2.840.10045.2.1");
+/// ```
+pub const fn oid_of<T: AssociatedOid>() -> ObjectIdentifier {
+    T::OID
+}
+
 /// A trait which associates a dynamic, `&self`-dependent OID with a type,
 /// which may change depending on the type's value.
 ///
@@ -23,3 +44,24 @@ impl<T: AssociatedOid> DynAssociatedOid for T {
         T::OID
     }
 }
+
+/// A trait which associates multiple OIDs with a type.
+///
+/// This is useful for e.g. algorithms which have been identified by more than
+/// one OID across versions of a standard, or for bundles of an algorithm
+/// along with its parameters.
+///
+/// This trait is auto-impl'd for any types which impl [`AssociatedOid`], as
+/// well as for pairs of such types.
+pub trait AssociatedOids {
+    /// The OIDs associated with this type.
+    const OIDS: &'static [ObjectIdentifier];
+}
+
+impl<T: AssociatedOid> AssociatedOids for T {
+    const OIDS: &'static [ObjectIdentifier] = &[T::OID];
+}
+
+impl<A: AssociatedOid, B: AssociatedOid> AssociatedOids for (A, B) {
+    const OIDS: &'static [ObjectIdentifier] = &[A::OID, B::OID];
+}
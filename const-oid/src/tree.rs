@@ -0,0 +1,166 @@
+//! Hierarchical OID trees, e.g. for OID browsers.
+
+use crate::{Arc, ObjectIdentifier, ObjectIdentifierRef};
+use alloc::{
+    collections::{btree_map, BTreeMap},
+    vec::Vec,
+};
+
+/// Tree of named OIDs, with each node's children keyed by arc.
+///
+/// Nodes are created for every prefix of each entry, so intermediate nodes
+/// which weren't themselves listed have no name or OID.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct OidTree<'a> {
+    /// Nodes for the root arcs.
+    roots: BTreeMap<Arc, OidTreeNode<'a>>,
+}
+
+impl<'a> OidTree<'a> {
+    /// Build a tree from a flat list of `(name, oid)` entries.
+    ///
+    /// If an OID is listed more than once, its first name is kept.
+    pub fn from_entries(entries: impl IntoIterator<Item = (&'a str, ObjectIdentifier)>) -> Self {
+        let mut tree = Self::default();
+
+        for (name, oid) in entries {
+            tree.insert(name, oid);
+        }
+
+        tree
+    }
+
+    /// Add a named OID to the tree.
+    pub fn insert(&mut self, name: &'a str, oid: ObjectIdentifier) {
+        let mut arcs = oid.arcs();
+
+        let Some(root) = arcs.next() else {
+            return;
+        };
+
+        let mut node = self
+            .roots
+            .entry(root)
+            .or_insert_with(|| OidTreeNode::new(root));
+
+        for arc in arcs {
+            node = node
+                .children
+                .entry(arc)
+                .or_insert_with(|| OidTreeNode::new(arc));
+        }
+
+        if node.entry.is_none() {
+            node.entry = Some((name, oid));
+        }
+    }
+
+    /// Get the nodes for the root arcs.
+    pub fn roots(&self) -> impl Iterator<Item = &OidTreeNode<'a>> {
+        self.roots.values()
+    }
+
+    /// Get the node for the given OID, if it is in the tree.
+    pub fn get(&self, oid: &ObjectIdentifierRef) -> Option<&OidTreeNode<'a>> {
+        let mut arcs = oid.arcs();
+        let mut node = self.roots.get(&arcs.next()?)?;
+
+        for arc in arcs {
+            node = node.children.get(&arc)?;
+        }
+
+        Some(node)
+    }
+
+    /// Get the immediate children of the given OID, ordered by arc.
+    ///
+    /// Returns `None` if the OID is not in the tree.
+    pub fn children_of(
+        &self,
+        oid: &ObjectIdentifierRef,
+    ) -> Option<impl Iterator<Item = &OidTreeNode<'a>>> {
+        self.get(oid).map(OidTreeNode::children)
+    }
+
+    /// Walk the tree depth-first (in pre-order, ordered by arc).
+    ///
+    /// Yields each node along with its depth, where root arcs have a depth of 0.
+    pub fn walk(&self) -> OidTreeWalk<'_, 'a> {
+        OidTreeWalk {
+            stack: alloc::vec![self.roots.values()],
+        }
+    }
+}
+
+/// Node of an [`OidTree`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OidTreeNode<'a> {
+    /// Arc of this node relative to its parent.
+    arc: Arc,
+
+    /// Name and OID, if this node was listed as an entry.
+    entry: Option<(&'a str, ObjectIdentifier)>,
+
+    /// Child nodes keyed by arc.
+    children: BTreeMap<Arc, OidTreeNode<'a>>,
+}
+
+impl<'a> OidTreeNode<'a> {
+    /// Create an empty node.
+    fn new(arc: Arc) -> Self {
+        Self {
+            arc,
+            entry: None,
+            children: BTreeMap::new(),
+        }
+    }
+
+    /// Get the arc of this node relative to its parent.
+    pub fn arc(&self) -> Arc {
+        self.arc
+    }
+
+    /// Get the name of this node, if it was listed as an entry.
+    pub fn name(&self) -> Option<&'a str> {
+        self.entry.map(|(name, _)| name)
+    }
+
+    /// Get the OID of this node, if it was listed as an entry.
+    pub fn oid(&self) -> Option<ObjectIdentifier> {
+        self.entry.map(|(_, oid)| oid)
+    }
+
+    /// Get the immediate children of this node, ordered by arc.
+    pub fn children(&self) -> impl Iterator<Item = &OidTreeNode<'a>> {
+        self.children.values()
+    }
+}
+
+/// Depth-first [`Iterator`] over the nodes of an [`OidTree`].
+///
+/// Returned by [`OidTree::walk`].
+#[derive(Clone, Debug)]
+pub struct OidTreeWalk<'t, 'a> {
+    /// Remaining siblings at each level of the current path.
+    stack: Vec<btree_map::Values<'t, Arc, OidTreeNode<'a>>>,
+}
+
+impl<'t, 'a> Iterator for OidTreeWalk<'t, 'a> {
+    type Item = (usize, &'t OidTreeNode<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let depth = self.stack.len().checked_sub(1)?;
+
+            match self.stack.last_mut()?.next() {
+                Some(node) => {
+                    self.stack.push(node.children.values());
+                    return Some((depth, node));
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+}
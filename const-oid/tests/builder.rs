@@ -0,0 +1,64 @@
+//! Tests for `OidBuilder`.
+
+use const_oid::{Error, ObjectIdentifier, OidBuilder};
+
+/// Build the OID of an X.509 extension, optionally under a private subtree with a version arc.
+fn extension_oid(private: bool, version: Option<u32>) -> Result<ObjectIdentifier, Error> {
+    let mut builder = OidBuilder::new();
+
+    if private {
+        builder.extend([1, 3, 6, 1, 4, 1, 55555]);
+    } else {
+        builder.extend([2, 5, 29]);
+    }
+
+    builder.push(15);
+
+    if let Some(version) = version {
+        builder.push(version);
+    }
+
+    builder.finish()
+}
+
+#[test]
+fn conditional_arcs() {
+    assert_eq!(
+        extension_oid(false, None),
+        Ok(ObjectIdentifier::new_unwrap("2.5.29.15"))
+    );
+    assert_eq!(
+        extension_oid(true, Some(2)),
+        Ok(ObjectIdentifier::new_unwrap("1.3.6.1.4.1.55555.15.2"))
+    );
+}
+
+#[test]
+fn validated_on_finish() {
+    assert_eq!(OidBuilder::new().finish(), Err(Error::NotEnoughArcs));
+
+    let mut builder = OidBuilder::default();
+    builder.extend([1, 2]);
+    assert_eq!(builder.clone().finish(), Err(Error::NotEnoughArcs));
+    builder.push(3);
+    assert_eq!(builder.finish(), Ok(ObjectIdentifier::new_unwrap("1.2.3")));
+
+    let mut builder = OidBuilder::new();
+    builder.extend([3, 1, 2]);
+    assert_eq!(builder.finish(), Err(Error::ArcInvalid { arc: 3 }));
+}
+
+#[test]
+fn too_many_arcs() {
+    // The largest possible OID of the default size
+    let mut builder = OidBuilder::new();
+    builder.extend([1, 2]);
+    builder.extend([0; ObjectIdentifier::MAX_SIZE - 1]);
+    assert!(builder.clone().finish().is_ok());
+
+    // Arcs beyond the buffer capacity are reported when finishing
+    builder.push(0);
+    assert_eq!(builder.clone().finish(), Err(Error::Length));
+    builder.extend([0; 100]);
+    assert_eq!(builder.finish(), Err(Error::Length));
+}
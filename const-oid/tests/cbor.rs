@@ -0,0 +1,97 @@
+//! CBOR (RFC 9090) encoding tests.
+
+#![cfg(feature = "cbor")]
+
+use const_oid::{Error, ObjectIdentifier};
+use hex_literal::hex;
+
+/// `1.2.840.113549`, from RFC 9090 Section 2.1.
+const RSADSI: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549");
+const RSADSI_CBOR: &[u8] = &hex!("D86F 46 2A864886F70D");
+
+/// `2.16.840.1.101.3.4.2.1` (SHA-256), from RFC 9090 Section 2.1.
+const SHA256: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.1");
+const SHA256_CBOR: &[u8] = &hex!("D86F 49 608648016503040201");
+
+#[test]
+fn from_cbor_bytes() {
+    assert_eq!(ObjectIdentifier::from_cbor_bytes(RSADSI_CBOR), Ok(RSADSI));
+    assert_eq!(ObjectIdentifier::from_cbor_bytes(SHA256_CBOR), Ok(SHA256));
+
+    // Byte strings of 24 bytes or more use a one-byte length
+    let long =
+        ObjectIdentifier::new_unwrap("1.3.6.1.4.1.311.21.20.1.2.3.4.5.6.7.8.9.10.11.12.13.14.15");
+    let mut cbor = hex!("D86F 58").to_vec();
+    cbor.push(long.as_bytes().len() as u8);
+    cbor.extend_from_slice(long.as_bytes());
+    assert_eq!(ObjectIdentifier::from_cbor_bytes(&cbor), Ok(long));
+}
+
+#[test]
+fn from_cbor_bytes_invalid() {
+    assert_eq!(ObjectIdentifier::from_cbor_bytes(&[]), Err(Error::Empty));
+
+    for cbor in [
+        // Untagged byte string
+        &hex!("46 2A864886F70D")[..],
+        // Relative OID tag
+        &hex!("D86E 46 2A864886F70D"),
+        // Non-preferred encodings of the tag and length
+        &hex!("D9006F 46 2A864886F70D"),
+        &hex!("D86F 5806 2A864886F70D"),
+        // Text string rather than byte string
+        &hex!("D86F 66 2A864886F70D"),
+        // Indefinite-length byte string
+        &hex!("D86F 5F 46 2A864886F70D FF"),
+    ] {
+        assert_eq!(
+            ObjectIdentifier::from_cbor_bytes(cbor),
+            Err(Error::CborInvalid),
+            "{:02X?}",
+            cbor
+        );
+    }
+
+    // Truncated
+    assert_eq!(
+        ObjectIdentifier::from_cbor_bytes(&hex!("D86F")),
+        Err(Error::Length)
+    );
+    assert_eq!(
+        ObjectIdentifier::from_cbor_bytes(&hex!("D86F 47 2A864886F70D")),
+        Err(Error::Length)
+    );
+
+    // Trailing data
+    assert_eq!(
+        ObjectIdentifier::from_cbor_bytes(&hex!("D86F 46 2A864886F70D 00")),
+        Err(Error::TrailingData)
+    );
+
+    // Invalid BER
+    assert_eq!(
+        ObjectIdentifier::from_cbor_bytes(&hex!("D86F 40")),
+        Err(Error::Empty)
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn to_cbor_bytes() {
+    assert_eq!(RSADSI.to_cbor_bytes(), RSADSI_CBOR);
+    assert_eq!(SHA256.to_cbor_bytes(), SHA256_CBOR);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn round_trip() {
+    for oid in [
+        RSADSI,
+        SHA256,
+        ObjectIdentifier::new_unwrap("1.3.6.1.4.1.311.21.20.1.2.3.4.5.6.7.8.9.10.11.12.13.14.15"),
+        ObjectIdentifier::new_unwrap("2.999.4294967295"),
+    ] {
+        let cbor = oid.to_cbor_bytes();
+        assert_eq!(ObjectIdentifier::from_cbor_bytes(&cbor), Ok(oid));
+    }
+}
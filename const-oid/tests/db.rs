@@ -0,0 +1,185 @@
+//! Tests for custom OID databases.
+
+#![cfg(feature = "db")]
+
+use const_oid::{db::Database, oid_db, ObjectIdentifier};
+
+const ALG_1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.4.1.55555.1");
+const ALG_2: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.4.1.55555.2");
+
+const MY_DB: Database<'static> = oid_db! {
+    "myAlgorithm" => "1.3.6.1.4.1.55555.1",
+    "myOtherAlgorithm" => "1.3.6.1.4.1.55555.2",
+    "myAlgorithmAlias" => "1.3.6.1.4.1.55555.1",
+};
+
+#[test]
+fn by_name() {
+    assert_eq!(MY_DB.by_name("myAlgorithm"), Some(&ALG_1));
+    assert_eq!(MY_DB.by_name("MYOTHERALGORITHM"), Some(&ALG_2));
+    assert_eq!(MY_DB.by_name("cn"), None);
+}
+
+#[test]
+fn by_oid() {
+    assert_eq!(MY_DB.by_oid(&ALG_1), Some("myAlgorithm"));
+    assert_eq!(MY_DB.by_oid(&ALG_2), Some("myOtherAlgorithm"));
+    assert_eq!(MY_DB.by_oid(&const_oid::db::rfc4519::CN), None);
+
+    let names: Vec<_> = MY_DB.find_names_for_oid(ALG_1).collect();
+    assert_eq!(names, ["myAlgorithm", "myAlgorithmAlias"]);
+}
+
+#[test]
+fn is_known() {
+    use const_oid::db::{rfc5912, DB};
+
+    assert!(MY_DB.is_known(&ALG_1));
+    assert!(!MY_DB.is_known(&rfc5912::RSA_ENCRYPTION));
+
+    assert!(DB.is_known(&rfc5912::RSA_ENCRYPTION));
+    assert!(!DB.is_known(&ALG_1));
+
+    assert!(rfc5912::ID_SHA_256.is_well_known());
+    assert!(!ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1.99").is_well_known());
+}
+
+#[test]
+fn typed_constants() {
+    use const_oid::db::{rfc4519, rfc5280, rfc5912, rfc8410};
+
+    for (constant, s) in [
+        (rfc5912::RSA_ENCRYPTION, "1.2.840.113549.1.1.1"),
+        (
+            rfc5912::SHA_256_WITH_RSA_ENCRYPTION,
+            "1.2.840.113549.1.1.11",
+        ),
+        (rfc5912::ECDSA_WITH_SHA_256, "1.2.840.10045.4.3.2"),
+        (rfc5912::ID_EC_PUBLIC_KEY, "1.2.840.10045.2.1"),
+        (rfc5912::ID_SHA_256, "2.16.840.1.101.3.4.2.1"),
+        (rfc5280::ID_CE_BASIC_CONSTRAINTS, "2.5.29.19"),
+        (rfc4519::CN, "2.5.4.3"),
+        (rfc8410::ID_ED_25519, "1.3.101.112"),
+    ] {
+        assert_eq!(constant, ObjectIdentifier::new_unwrap(s));
+        assert_eq!(constant, s.parse::<ObjectIdentifier>().unwrap());
+    }
+
+    // Usable as patterns
+    let is_rsa = |oid| matches!(oid, rfc5912::RSA_ENCRYPTION);
+    assert!(is_rsa(ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1")));
+    assert!(!is_rsa(rfc5912::ID_EC_PUBLIC_KEY));
+}
+
+#[test]
+fn empty() {
+    const EMPTY: Database<'static> = oid_db! {};
+    assert_eq!(EMPTY.by_name("myAlgorithm"), None);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn to_oid_iri() {
+    use const_oid::db::{rfc5912, DB};
+
+    assert_eq!(
+        rfc5912::RSA_ENCRYPTION.to_oid_iri(&DB),
+        "/ISO/2/840/rsadsi/1/pkcs-1/rsaEncryption"
+    );
+
+    // Unnamed arcs are rendered numerically
+    let oid = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1.99");
+    assert_eq!(
+        oid.to_oid_iri(&DB),
+        "/ISO/2/840/rsadsi/1/pkcs-1/rsaEncryption/99"
+    );
+    assert_eq!(ALG_1.to_oid_iri(&MY_DB), "/ISO/3/6/1/4/1/55555/myAlgorithm");
+    assert_eq!(
+        ObjectIdentifier::new_unwrap("2.999.1").to_oid_iri(&MY_DB),
+        "/Joint-ISO-ITU-T/999/1"
+    );
+}
+
+#[test]
+fn from_oid_iri() {
+    use const_oid::{db::rfc5912, db::DB, Error};
+
+    // Named arcs
+    assert_eq!(
+        ObjectIdentifier::from_oid_iri("/ISO/2/840/rsadsi/1/pkcs-1/rsaEncryption", &DB),
+        Ok(rfc5912::RSA_ENCRYPTION)
+    );
+    assert_eq!(
+        ObjectIdentifier::from_oid_iri("/iso/2/840/113549/1/1/1", &DB),
+        Ok(rfc5912::RSA_ENCRYPTION)
+    );
+    assert_eq!(
+        ObjectIdentifier::from_oid_iri("/ISO/3/6/1/4/1/55555/myAlgorithmAlias", &MY_DB),
+        Ok(ALG_1)
+    );
+
+    // Numeric arcs, including the root
+    assert_eq!(
+        ObjectIdentifier::from_oid_iri("/1/3/6/1/4/1/55555/2", &MY_DB),
+        Ok(ALG_2)
+    );
+    assert_eq!(
+        ObjectIdentifier::from_oid_iri("/Joint-ISO-ITU-T/999/1", &MY_DB),
+        Ok(ObjectIdentifier::new_unwrap("2.999.1"))
+    );
+
+    // Unknown names, including names which belong under a different arc
+    assert_eq!(
+        ObjectIdentifier::from_oid_iri("/ISO/2/840/rsadsi/1/pkcs-1/purplePeopleEater", &DB),
+        Err(Error::LabelInvalid)
+    );
+    assert_eq!(
+        ObjectIdentifier::from_oid_iri("/ISO/3/6/1/4/1/55555/3/myAlgorithm", &MY_DB),
+        Err(Error::LabelInvalid)
+    );
+    assert_eq!(
+        ObjectIdentifier::from_oid_iri("/Mars/2/840", &DB),
+        Err(Error::LabelInvalid)
+    );
+
+    // Malformed
+    assert_eq!(ObjectIdentifier::from_oid_iri("", &DB), Err(Error::Empty));
+    assert_eq!(
+        ObjectIdentifier::from_oid_iri("ISO/2/840", &DB),
+        Err(Error::LabelInvalid)
+    );
+    assert_eq!(
+        ObjectIdentifier::from_oid_iri("/ISO/2/840/", &DB),
+        Err(Error::LabelInvalid)
+    );
+    assert_eq!(
+        ObjectIdentifier::from_oid_iri("/ISO/40/1", &DB),
+        Err(Error::ArcInvalid { arc: 40 })
+    );
+    assert_eq!(
+        ObjectIdentifier::from_oid_iri("/3/2/1", &DB),
+        Err(Error::ArcInvalid { arc: 3 })
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn oid_iri_round_trip() {
+    use const_oid::db::{rfc5912, DB};
+
+    for oid in [
+        rfc5912::RSA_ENCRYPTION,
+        rfc5912::ID_SHA_256,
+        rfc5912::ECDSA_WITH_SHA_384,
+        ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1.99"),
+        ObjectIdentifier::new_unwrap("2.999.1"),
+    ] {
+        let iri = oid.to_oid_iri(&DB);
+        assert_eq!(
+            ObjectIdentifier::from_oid_iri(&iri, &DB),
+            Ok(oid),
+            "{}",
+            iri
+        );
+    }
+}
@@ -0,0 +1,70 @@
+//! Tests for `OidMap`.
+
+use const_oid::{ObjectIdentifier, OidMap};
+
+const RSA_ENCRYPTION: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+const SHA256_WITH_RSA: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.11");
+const EC_PUBLIC_KEY: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+const ED25519: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.112");
+
+#[test]
+fn insert_and_get() {
+    let mut map = OidMap::<&str, 4>::new();
+    assert!(map.is_empty());
+
+    assert_eq!(
+        map.insert(SHA256_WITH_RSA, "sha256WithRSAEncryption"),
+        Ok(None)
+    );
+    assert_eq!(map.insert(ED25519, "Ed25519"), Ok(None));
+    assert_eq!(map.insert(RSA_ENCRYPTION, "rsaEncryption"), Ok(None));
+    assert_eq!(map.len(), 3);
+
+    assert_eq!(map.get(&RSA_ENCRYPTION), Some(&"rsaEncryption"));
+    assert_eq!(map.get(&SHA256_WITH_RSA), Some(&"sha256WithRSAEncryption"));
+    assert_eq!(map.get(&ED25519), Some(&"Ed25519"));
+    assert_eq!(map.get(&EC_PUBLIC_KEY), None);
+    assert!(!map.contains_key(&EC_PUBLIC_KEY));
+}
+
+#[test]
+fn insert_replaces_existing() {
+    let mut map = OidMap::<u8, 1>::new();
+    assert_eq!(map.insert(ED25519, 1), Ok(None));
+    assert_eq!(map.insert(ED25519, 2), Ok(Some(1)));
+    assert_eq!(map.get(&ED25519), Some(&2));
+    assert_eq!(map.len(), 1);
+}
+
+#[test]
+fn insert_when_full() {
+    let mut map = OidMap::<u8, 2>::new();
+    assert_eq!(map.insert(ED25519, 1), Ok(None));
+    assert_eq!(map.insert(RSA_ENCRYPTION, 2), Ok(None));
+    assert_eq!(map.insert(EC_PUBLIC_KEY, 3), Err((EC_PUBLIC_KEY, 3)));
+    assert_eq!(map.get(&EC_PUBLIC_KEY), None);
+}
+
+#[test]
+fn iter_sorted() {
+    let mut map = OidMap::<u8, 4>::new();
+    map.insert(SHA256_WITH_RSA, 1).unwrap();
+    map.insert(ED25519, 2).unwrap();
+    map.insert(EC_PUBLIC_KEY, 3).unwrap();
+    map.insert(RSA_ENCRYPTION, 4).unwrap();
+
+    let keys = map.iter().map(|(key, _)| *key).collect::<Vec<_>>();
+    assert_eq!(
+        keys,
+        [RSA_ENCRYPTION, SHA256_WITH_RSA, EC_PUBLIC_KEY, ED25519]
+    );
+}
+
+#[test]
+fn debug() {
+    let mut map = OidMap::<u8, 2>::new();
+    assert_eq!(format!("{:?}", map), "{}");
+
+    map.insert(ED25519, 1).unwrap();
+    assert_eq!(format!("{:?}", map), "{ObjectIdentifier(1.3.101.112): 1}");
+}
@@ -3,7 +3,9 @@
 // TODO(tarcieri): test full set of OID encoding constraints specified here:
 // <https://misc.daniel-marschall.de/asn.1/oid_facts.html>
 
-use const_oid::{Error, ObjectIdentifier};
+use const_oid::{
+    encode_arcs_into, find_prefix_conflicts, Arc, Error, ErrorKind, ObjectIdentifier, RootAuthority,
+};
 use hex_literal::hex;
 use std::string::ToString;
 
@@ -64,6 +66,109 @@ fn from_bytes() {
     assert_eq!(ObjectIdentifier::from_bytes(&[]), Err(Error::Empty));
 }
 
+#[test]
+fn from_value_prefix() {
+    let bytes = [EXAMPLE_OID_1_BER, &hex!("0500FF")].concat();
+    assert_eq!(
+        ObjectIdentifier::from_value_prefix(&bytes, EXAMPLE_OID_1_BER.len()),
+        Ok((EXAMPLE_OID_1, hex!("0500FF").as_slice()))
+    );
+
+    // No trailing bytes
+    assert_eq!(
+        ObjectIdentifier::from_value_prefix(EXAMPLE_OID_2_BER, EXAMPLE_OID_2_BER.len()),
+        Ok((EXAMPLE_OID_2, [].as_slice()))
+    );
+
+    // Shorter value which is still a valid OID
+    assert_eq!(
+        ObjectIdentifier::from_value_prefix(&bytes, 3),
+        Ok((oid("1.2.840"), &bytes[3..]))
+    );
+
+    // Value length splits an arc, exceeds the input, or is zero
+    assert_eq!(
+        ObjectIdentifier::from_value_prefix(&bytes, 2),
+        Err(Error::Base128 { offset: 1 })
+    );
+    assert_eq!(
+        ObjectIdentifier::from_value_prefix(&bytes, bytes.len() + 1),
+        Err(Error::Length)
+    );
+    assert_eq!(
+        ObjectIdentifier::from_value_prefix(&bytes, 0),
+        Err(Error::Empty)
+    );
+}
+
+#[test]
+fn from_bytes_bounded() {
+    // EXAMPLE_OID_2 has 9 arcs
+    assert_eq!(
+        ObjectIdentifier::from_bytes_bounded(EXAMPLE_OID_2_BER, 9),
+        Ok(EXAMPLE_OID_2)
+    );
+    assert_eq!(
+        ObjectIdentifier::from_bytes_bounded(EXAMPLE_OID_2_BER, usize::MAX),
+        Ok(EXAMPLE_OID_2)
+    );
+    assert_eq!(
+        ObjectIdentifier::from_bytes_bounded(EXAMPLE_OID_2_BER, 8),
+        Err(Error::TooManyArcs)
+    );
+    assert_eq!(
+        ObjectIdentifier::from_bytes_bounded(EXAMPLE_OID_2_BER, 0),
+        Err(Error::TooManyArcs)
+    );
+    assert_eq!(Error::TooManyArcs.kind(), ErrorKind::Length);
+
+    // The limit is hit before the malformed trailing arc is reached
+    assert_eq!(
+        ObjectIdentifier::from_bytes_bounded(&hex!("2A0102030405FF"), 4),
+        Err(Error::TooManyArcs)
+    );
+    assert_eq!(
+        ObjectIdentifier::from_bytes_bounded(&hex!("2A0102030405FF"), 100),
+        Err(Error::Base128 { offset: 6 })
+    );
+
+    // Empty
+    assert_eq!(
+        ObjectIdentifier::from_bytes_bounded(&[], 100),
+        Err(Error::Empty)
+    );
+}
+
+#[test]
+fn from_bytes_expecting() {
+    // EXAMPLE_OID_1 has 6 arcs
+    assert_eq!(
+        ObjectIdentifier::from_bytes_expecting(EXAMPLE_OID_1_BER, 6),
+        Ok(EXAMPLE_OID_1)
+    );
+    assert_eq!(
+        ObjectIdentifier::from_bytes_expecting(EXAMPLE_OID_1_BER, 5),
+        Err(Error::TooManyArcs)
+    );
+    assert_eq!(
+        ObjectIdentifier::from_bytes_expecting(EXAMPLE_OID_1_BER, 7),
+        Err(Error::TooFewArcs)
+    );
+    assert_eq!(Error::TooFewArcs.kind(), ErrorKind::Length);
+
+    // A truncated value of the expected OID has fewer arcs
+    assert_eq!(
+        ObjectIdentifier::from_bytes_expecting(&EXAMPLE_OID_1_BER[..5], 6),
+        Err(Error::TooFewArcs)
+    );
+
+    // Malformed input is still rejected
+    assert_eq!(
+        ObjectIdentifier::from_bytes_expecting(&hex!("2A0102FF"), 6),
+        Err(Error::Base128 { offset: 3 })
+    );
+}
+
 #[test]
 fn from_str() {
     let oid0 = EXAMPLE_OID_0_STR.parse::<ObjectIdentifier>().unwrap();
@@ -112,6 +217,24 @@ fn from_str() {
     );
 }
 
+#[test]
+fn debug() {
+    assert_eq!(
+        format!("{:?}", EXAMPLE_OID_1),
+        "ObjectIdentifier(1.2.840.10045.2.1)"
+    );
+
+    let alternate = format!("{:#?}", EXAMPLE_OID_1);
+    assert!(alternate.starts_with("ObjectIdentifier {\n"));
+    assert!(alternate.contains("oid: 1.2.840.10045.2.1,"));
+    assert!(alternate.contains("10045,"));
+    assert!(alternate.contains("bytes: 2A8648CE3D0201,"));
+
+    let alternate = format!("{:#?}", EXAMPLE_OID_1.as_oid_ref());
+    assert!(alternate.starts_with("ObjectIdentifierRef {\n"));
+    assert!(alternate.contains("bytes: 2A8648CE3D0201,"));
+}
+
 #[test]
 fn display() {
     assert_eq!(EXAMPLE_OID_0.to_string(), EXAMPLE_OID_0_STR);
@@ -120,6 +243,120 @@ fn display() {
     assert_eq!(EXAMPLE_OID_LARGE_ARC.to_string(), EXAMPLE_OID_LARGE_ARC_STR);
 }
 
+/// Writes into a fixed-capacity buffer defined here rather than a `heapless::String`, since
+/// `heapless` is not a dependency of this crate.
+#[test]
+fn write_dotted() {
+    /// Fixed-capacity `fmt::Write` which fails rather than allocating.
+    struct FixedBuf<const N: usize> {
+        bytes: [u8; N],
+        len: usize,
+    }
+
+    impl<const N: usize> core::fmt::Write for FixedBuf<N> {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let end = self.len + s.len();
+            self.bytes
+                .get_mut(self.len..end)
+                .ok_or(core::fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    for (oid, s) in [
+        (EXAMPLE_OID_0, EXAMPLE_OID_0_STR),
+        (EXAMPLE_OID_1, EXAMPLE_OID_1_STR),
+        (EXAMPLE_OID_2, EXAMPLE_OID_2_STR),
+        (EXAMPLE_OID_LARGE_ARC, EXAMPLE_OID_LARGE_ARC_STR),
+    ] {
+        let mut buf = FixedBuf {
+            bytes: [0u8; 64],
+            len: 0,
+        };
+        oid.write_dotted(&mut buf).unwrap();
+        assert_eq!(&buf.bytes[..buf.len], s.as_bytes());
+    }
+
+    // Arcs of every width, including zero and `u32::MAX`
+    let oid = ObjectIdentifier::from_arcs([2, 0, 9, 10, 99, 100, u32::MAX]).unwrap();
+    let mut buf = FixedBuf {
+        bytes: [0u8; 64],
+        len: 0,
+    };
+    oid.write_dotted(&mut buf).unwrap();
+    assert_eq!(&buf.bytes[..buf.len], oid.to_string().as_bytes());
+
+    // Errors from the writer are propagated
+    let mut buf = FixedBuf {
+        bytes: [0u8; 8],
+        len: 0,
+    };
+    assert!(EXAMPLE_OID_1.write_dotted(&mut buf).is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn try_from_heap_bytes() {
+    let vec = EXAMPLE_OID_1_BER.to_vec();
+    assert_eq!(ObjectIdentifier::try_from(&vec), Ok(EXAMPLE_OID_1));
+
+    let boxed: Box<[u8]> = EXAMPLE_OID_2_BER.into();
+    assert_eq!(ObjectIdentifier::try_from(boxed), Ok(EXAMPLE_OID_2));
+
+    // Invalid contents
+    assert_eq!(ObjectIdentifier::try_from(&Vec::new()), Err(Error::Empty));
+    assert_eq!(
+        ObjectIdentifier::try_from(Box::<[u8]>::from(hex!("2A86").as_slice())),
+        Err(Error::Base128 { offset: 1 })
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn parse_list() {
+    use const_oid::ParseListError;
+
+    let server_auth = oid("1.3.6.1.5.5.7.3.1");
+    let client_auth = oid("1.3.6.1.5.5.7.3.2");
+
+    // Comma-separated
+    assert_eq!(
+        ObjectIdentifier::parse_list("1.3.6.1.5.5.7.3.1,1.3.6.1.5.5.7.3.2"),
+        Ok(vec![server_auth, client_auth])
+    );
+
+    // Space-separated
+    assert_eq!(
+        ObjectIdentifier::parse_list("1.3.6.1.5.5.7.3.1 \t1.3.6.1.5.5.7.3.2\n"),
+        Ok(vec![server_auth, client_auth])
+    );
+
+    // Mixed separators, trailing comma
+    assert_eq!(
+        ObjectIdentifier::parse_list(" 1.3.6.1.5.5.7.3.1, 1.3.6.1.5.5.7.3.2, "),
+        Ok(vec![server_auth, client_auth])
+    );
+    assert_eq!(ObjectIdentifier::parse_list(""), Ok(vec![]));
+
+    // One invalid entry
+    assert_eq!(
+        ObjectIdentifier::parse_list("1.3.6.1.5.5.7.3.1, 1.3.6.1.5.5.7.3.x, 1.3.6.1.5.5.7.3.2"),
+        Err(ParseListError {
+            index: 1,
+            error: Error::DigitExpected { actual: b'x' }
+        })
+    );
+    assert_eq!(
+        ObjectIdentifier::parse_list("1.3.6.1.5.5.7.3.1 3.2.1")
+            .unwrap_err()
+            .to_string(),
+        "OID list element 1: OID contains out-of-range arc: 3 (the first arc must be 0-2, and \
+         the second 0-39 unless the first arc is 2)"
+    );
+}
+
 #[test]
 fn try_from_u32_slice() {
     let oid1 = ObjectIdentifier::from_arcs([1, 2, 840, 10045, 2, 1]).unwrap();
@@ -145,12 +382,61 @@ fn try_from_u32_slice() {
     );
 }
 
+#[test]
+fn from_arcs_not_enough_arcs() {
+    assert_eq!(ObjectIdentifier::from_arcs([]), Err(Error::NotEnoughArcs));
+    assert_eq!(ObjectIdentifier::from_arcs([1]), Err(Error::NotEnoughArcs));
+    assert_eq!(
+        ObjectIdentifier::from_arcs([1, 2]),
+        Err(Error::NotEnoughArcs)
+    );
+    assert_eq!(
+        ObjectIdentifier::from_arcs([2, 999]),
+        Err(Error::NotEnoughArcs)
+    );
+    assert!(ObjectIdentifier::from_arcs([1, 2, 3]).is_ok());
+
+    // Invalid arcs are still reported as such
+    assert_eq!(
+        ObjectIdentifier::from_arcs([3]),
+        Err(Error::ArcInvalid { arc: 3 })
+    );
+}
+
+#[test]
+fn from_single_arc() {
+    for arc in [0, 1, 2, 42] {
+        assert_eq!(
+            ObjectIdentifier::from_single_arc(arc),
+            Err(Error::NotEnoughArcs)
+        );
+    }
+
+    assert_eq!(Error::NotEnoughArcs.kind(), ErrorKind::Length);
+}
+
 #[test]
 fn as_bytes() {
     assert_eq!(EXAMPLE_OID_1.as_bytes(), EXAMPLE_OID_1_BER);
     assert_eq!(EXAMPLE_OID_2.as_bytes(), EXAMPLE_OID_2_BER);
 }
 
+#[test]
+fn value_bytes() {
+    // Includes OIDs derived from longer ones, which must not leave stale bytes behind
+    for oid in [
+        EXAMPLE_OID_1,
+        EXAMPLE_OID_2,
+        EXAMPLE_OID_2.parent().unwrap(),
+        EXAMPLE_OID_2.set_arc(8, 1).unwrap(),
+    ] {
+        let (bytes, len) = oid.value_bytes();
+        assert_eq!(bytes.len(), 39);
+        assert_eq!(&bytes[..len], oid.as_bytes());
+        assert!(bytes[len..].iter().all(|&b| b == 0));
+    }
+}
+
 #[test]
 fn as_oid_ref() {
     assert_eq!(
@@ -159,11 +445,137 @@ fn as_oid_ref() {
     );
 }
 
+#[test]
+fn large_second_arc() {
+    // Example from X.690 section 8.19.5
+    let oid = ObjectIdentifier::from_arcs([2, 999, 3]).unwrap();
+    assert_eq!(oid.as_bytes(), &hex!("883703"));
+    assert_eq!(oid, "2.999.3".parse::<ObjectIdentifier>().unwrap());
+    assert_eq!(oid.to_string(), "2.999.3");
+    assert_eq!(ObjectIdentifier::from_bytes(&hex!("883703")).unwrap(), oid);
+    assert!(oid.arcs().eq([2, 999, 3]));
+    assert_eq!(oid.first_arc(), 2);
+    assert_eq!(oid.second_arc(), 999);
+
+    let oid = ObjectIdentifier::new_unwrap("2.999.1");
+    assert_eq!(ObjectIdentifier::from_bytes(oid.as_bytes()).unwrap(), oid);
+    assert!(oid.arcs().eq([2, 999, 1]));
+    assert_eq!(oid.parent(), Some(ObjectIdentifier::new_unwrap("2.999")));
+
+    // Boundary between single and multi-byte first groups
+    assert_eq!(
+        ObjectIdentifier::new_unwrap("2.47.1").as_bytes(),
+        &hex!("7F01")
+    );
+    assert_eq!(
+        ObjectIdentifier::new_unwrap("2.48.1").as_bytes(),
+        &hex!("810001")
+    );
+    assert!(ObjectIdentifier::from_bytes(&hex!("810001"))
+        .unwrap()
+        .arcs()
+        .eq([2, 48, 1]));
+
+    // Largest second arc whose combined value fits in an `Arc`
+    let max = u32::MAX - 80;
+    let oid = ObjectIdentifier::from_arcs([2, max, 1]).unwrap();
+    assert_eq!(oid.as_bytes(), &hex!("8FFFFFFF7F01"));
+    assert!(oid.arcs().eq([2, max, 1]));
+    assert_eq!(
+        ObjectIdentifier::from_arcs([2, max + 1, 1]),
+        Err(Error::ArcTooBig)
+    );
+
+    // The second arc is still limited for the other roots
+    assert_eq!(
+        ObjectIdentifier::from_arcs([1, 40, 1]),
+        Err(Error::ArcInvalid { arc: 40 })
+    );
+}
+
+/// Exercise `ObjectIdentifier::new`, which must return a `Result` rather than panic for every
+/// input.
+#[test]
+fn parse_never_panics() {
+    /// Parse every input of up to `depth` more characters from a small alphabet.
+    fn parse_all(input: &mut String, depth: usize) {
+        let _ = ObjectIdentifier::new(input);
+
+        if depth > 0 {
+            for c in ['0', '1', '2', '9', '.', 'x'] {
+                input.push(c);
+                parse_all(input, depth - 1);
+                input.pop();
+            }
+        }
+    }
+
+    parse_all(&mut String::new(), 6);
+
+    // Arcs which overflow `Arc`
+    for s in [
+        "4294967296.1.1",
+        "1.4294967296.1",
+        "1.2.4294967296",
+        "1.2.42949672950",
+        "1.2.99999999999999999999999999999999999999",
+    ] {
+        assert_eq!(ObjectIdentifier::new(s), Err(Error::ArcTooBig), "{s}");
+    }
+
+    // Very long inputs, which might otherwise exhaust the stack
+    let zeros = format!("1.2.{}", "0".repeat(1_000_000));
+    assert_eq!(ObjectIdentifier::new(&zeros), Ok(oid("1.2.0")));
+
+    let arcs = format!("1.2{}", ".1".repeat(1_000_000));
+    assert_eq!(ObjectIdentifier::new(&arcs), Err(Error::Length));
+}
+
 #[test]
 fn parse_empty() {
     assert_eq!(ObjectIdentifier::new(""), Err(Error::Empty));
 }
 
+#[test]
+#[should_panic(expected = "invalid length: OID value is empty")]
+fn new_unwrap_panic_message() {
+    ObjectIdentifier::new_unwrap("");
+}
+
+#[test]
+#[should_panic(expected = "arc out of range: OID contains out-of-range arc")]
+fn new_unwrap_panic_message_arc() {
+    ObjectIdentifier::new_unwrap("3.1.2");
+}
+
+#[test]
+fn parse_degenerate_inputs() {
+    assert_eq!(ObjectIdentifier::new(""), Err(Error::Empty));
+    assert_eq!(ObjectIdentifier::new("."), Err(Error::LeadingDot));
+    assert_eq!(ObjectIdentifier::new("1"), Err(Error::NotEnoughArcs));
+    assert_eq!(ObjectIdentifier::new("1."), Err(Error::TrailingDot));
+    assert_eq!(ObjectIdentifier::new(".1"), Err(Error::LeadingDot));
+    assert_eq!(
+        ObjectIdentifier::new("1..2"),
+        Err(Error::DigitExpected { actual: b'.' })
+    );
+
+    // The runtime parsers classify these the same way
+    for s in ["", ".", "1", "1.", ".1", "1..2"] {
+        let expected = ObjectIdentifier::new(s);
+        assert_eq!(ObjectIdentifier::new_lenient(s), expected, "{}", s);
+        assert_eq!(
+            ObjectIdentifier::from_ascii_iter(s.bytes()),
+            expected,
+            "{}",
+            s
+        );
+        assert_eq!(s.parse::<ObjectIdentifier>(), expected, "{}", s);
+    }
+
+    assert_eq!(Error::LeadingDot.kind(), ErrorKind::Parse);
+}
+
 #[test]
 fn parse_invalid_first_arc() {
     assert_eq!(
@@ -191,6 +603,114 @@ fn parent() {
     assert_eq!(parent.parent(), None);
 }
 
+#[test]
+fn common_prefix() {
+    let a = oid("1.3.6.1.4.1.311.21.20");
+    let b = oid("1.3.6.1.4.1.11129.2.4.2");
+    assert_eq!(a.common_prefix(&b), Some(oid("1.3.6.1.4.1")));
+    assert_eq!(a.common_prefix(&a), Some(a));
+
+    let parent = oid("1.3.6.1.4.1.311");
+    assert_eq!(a.common_prefix(&parent), Some(parent));
+    assert_eq!(parent.common_prefix(&a), Some(parent));
+
+    // Fewer than 3 arcs in common
+    assert_eq!(a.common_prefix(&oid("1.3.7.1")), None);
+    assert_eq!(a.common_prefix(&EXAMPLE_OID_2), None);
+}
+
+#[test]
+fn shared_arc_count() {
+    let a = oid("1.3.6.1.4.1.311.21.20");
+    assert_eq!(a.shared_arc_count(&oid("1.3.6.1.4.1.11129.2.4.2")), 6);
+    assert_eq!(a.shared_arc_count(&a), 9);
+    assert_eq!(a.shared_arc_count(&oid("1.3.6.1.4.1.311")), 7);
+
+    // Fewer than the 3 arcs of a valid OID
+    assert_eq!(a.shared_arc_count(&oid("1.3.7.1")), 2);
+    assert_eq!(a.common_prefix(&oid("1.3.7.1")), None);
+    assert_eq!(a.shared_arc_count(&oid("1.2.3")), 1);
+    assert_eq!(a.shared_arc_count(&EXAMPLE_OID_2), 0);
+}
+
+#[test]
+fn common_prefix_of() {
+    let policies = [
+        oid("2.23.140.1.2.1"),
+        oid("2.23.140.1.2.2"),
+        oid("2.23.140.1.1"),
+        oid("2.23.140.1.3"),
+    ];
+    assert_eq!(
+        ObjectIdentifier::common_prefix_of(&policies),
+        Some(oid("2.23.140.1"))
+    );
+    assert_eq!(
+        ObjectIdentifier::common_prefix_of(&[EXAMPLE_OID_1]),
+        Some(EXAMPLE_OID_1)
+    );
+
+    // Disjoint
+    assert_eq!(
+        ObjectIdentifier::common_prefix_of(&[EXAMPLE_OID_0, EXAMPLE_OID_1, EXAMPLE_OID_2]),
+        None
+    );
+    assert_eq!(
+        ObjectIdentifier::common_prefix_of(&[oid("1.2.840.1"), oid("1.2.841.1")]),
+        None
+    );
+    assert_eq!(ObjectIdentifier::common_prefix_of(&[]), None);
+}
+
+#[test]
+fn resize() {
+    let small = EXAMPLE_OID_1.resize::<16>().unwrap();
+    assert_eq!(small.as_bytes(), EXAMPLE_OID_1_BER);
+    assert_eq!(small.to_string(), EXAMPLE_OID_1_STR);
+
+    // Exactly fits
+    let exact = EXAMPLE_OID_1.resize::<7>().unwrap();
+    assert_eq!(exact.as_bytes(), EXAMPLE_OID_1_BER);
+
+    // Round trip back to the default size
+    assert_eq!(exact.resize::<39>().unwrap(), EXAMPLE_OID_1);
+
+    assert_eq!(EXAMPLE_OID_1.resize::<6>(), Err(Error::Length));
+}
+
+#[test]
+fn fits_in() {
+    assert!(EXAMPLE_OID_1.fits_in::<39>());
+    assert!(EXAMPLE_OID_1.fits_in::<8>());
+    assert!(EXAMPLE_OID_1.fits_in::<7>());
+    assert!(!EXAMPLE_OID_1.fits_in::<6>());
+    assert!(!EXAMPLE_OID_1.fits_in::<0>());
+
+    assert_eq!(
+        EXAMPLE_OID_1.fits_in::<7>(),
+        EXAMPLE_OID_1.resize::<7>().is_ok()
+    );
+    assert_eq!(
+        EXAMPLE_OID_1.fits_in::<6>(),
+        EXAMPLE_OID_1.resize::<6>().is_ok()
+    );
+}
+
+#[test]
+fn pop_arc() {
+    let (parent, arc) = EXAMPLE_OID_1.pop_arc().unwrap();
+    assert_eq!(parent, oid("1.2.840.10045.2"));
+    assert_eq!(arc, 1);
+
+    let (parent, arc) = oid("1.2.3.4294967295").pop_arc().unwrap();
+    assert_eq!(parent, oid("1.2.3"));
+    assert_eq!(arc, u32::MAX);
+
+    // Popping would leave fewer than 3 arcs
+    assert_eq!(oid("1.2.3").pop_arc(), None);
+    assert_eq!(oid("1.2").pop_arc(), None);
+}
+
 #[test]
 fn push_arc() {
     let parent = oid("1.2.3");
@@ -198,13 +718,1442 @@ fn push_arc() {
 }
 
 #[test]
-fn starts_with() {
-    let child = ObjectIdentifier::new("1.2.3.4.5").unwrap();
-    assert!(child.starts_with(oid("1.2.3.4.5")));
-    assert!(child.starts_with(oid("1.2.3.4")));
-    assert!(child.starts_with(oid("1.2.3")));
+fn new_lenient() {
+    assert_eq!(
+        ObjectIdentifier::new_lenient("1.2.840.113_549").unwrap(),
+        oid("1.2.840.113549")
+    );
+    assert_eq!(
+        ObjectIdentifier::new_lenient("0.9.2_342.19_200_300.100.1.1").unwrap(),
+        EXAMPLE_OID_0
+    );
+    assert_eq!(
+        ObjectIdentifier::new_lenient(EXAMPLE_OID_1_STR).unwrap(),
+        EXAMPLE_OID_1
+    );
 
-    assert!(!child.starts_with(oid("1.2.4")));
-    assert!(!child.starts_with(oid("2.2.3")));
-    assert!(!child.starts_with(oid("1.2.3.4.5.6")));
+    // Strict parsing is unchanged
+    assert_eq!(
+        ObjectIdentifier::new("1.2.840.113_549"),
+        Err(Error::DigitExpected { actual: b'_' })
+    );
+}
+
+#[test]
+fn new_lenient_invalid() {
+    for s in ["1._2.3", "1.2_.3", "1.2.3__4", "_1.2.3", "1.2._", "1.2.3_"] {
+        assert_eq!(
+            ObjectIdentifier::new_lenient(s),
+            Err(Error::DigitExpected { actual: b'_' }),
+            "{s}"
+        );
+    }
+
+    assert_eq!(ObjectIdentifier::new_lenient(""), Err(Error::Empty));
+    assert_eq!(
+        ObjectIdentifier::new_lenient("1.2.3."),
+        Err(Error::TrailingDot)
+    );
+    assert_eq!(
+        ObjectIdentifier::new_lenient("1.2..3"),
+        Err(Error::DigitExpected { actual: b'.' })
+    );
+    assert_eq!(
+        ObjectIdentifier::new_lenient("1.40.1"),
+        Err(Error::ArcInvalid { arc: 40 })
+    );
+}
+
+#[test]
+fn new_symbolic() {
+    assert_eq!(
+        ObjectIdentifier::new_symbolic("iso.3.6.1").unwrap(),
+        oid("1.3.6.1")
+    );
+    assert_eq!(
+        ObjectIdentifier::new_symbolic("ccitt.0.1").unwrap(),
+        oid("0.0.1")
+    );
+    assert_eq!(
+        ObjectIdentifier::new_symbolic("joint-iso-ccitt.16.840").unwrap(),
+        oid("2.16.840")
+    );
+    assert_eq!(
+        ObjectIdentifier::new_symbolic("joint-iso-itu-t.16.840").unwrap(),
+        oid("2.16.840")
+    );
+
+    // Purely numeric OIDs are parsed as usual
+    assert_eq!(
+        ObjectIdentifier::new_symbolic(EXAMPLE_OID_1_STR).unwrap(),
+        EXAMPLE_OID_1
+    );
+}
+
+#[test]
+fn new_symbolic_invalid() {
+    assert_eq!(
+        ObjectIdentifier::new_symbolic("iso"),
+        Err(Error::NotEnoughArcs)
+    );
+    assert_eq!(
+        ObjectIdentifier::new_symbolic("iso."),
+        Err(Error::TrailingDot)
+    );
+    assert_eq!(
+        ObjectIdentifier::new_symbolic("iso.40.1"),
+        Err(Error::ArcInvalid { arc: 40 })
+    );
+    assert_eq!(
+        ObjectIdentifier::new_symbolic("iso.3.dod"),
+        Err(Error::DigitExpected { actual: b'd' })
+    );
+    assert_eq!(
+        ObjectIdentifier::new_symbolic("ISO.3.6.1"),
+        Err(Error::DigitExpected { actual: b'I' })
+    );
+    assert_eq!(
+        ObjectIdentifier::new_symbolic("1.iso.3"),
+        Err(Error::DigitExpected { actual: b'i' })
+    );
+}
+
+#[test]
+fn parse_prefix_numeric() {
+    // With trailing labels
+    assert_eq!(
+        ObjectIdentifier::parse_prefix_numeric("2.5.4.3 (commonName)"),
+        Ok((oid("2.5.4.3"), " (commonName)"))
+    );
+    assert_eq!(
+        ObjectIdentifier::parse_prefix_numeric("1.2.840.10045.2.1\tid-ecPublicKey"),
+        Ok((EXAMPLE_OID_1, "\tid-ecPublicKey"))
+    );
+    assert_eq!(
+        ObjectIdentifier::parse_prefix_numeric("1.2.840.10045.2.1:"),
+        Ok((EXAMPLE_OID_1, ":"))
+    );
+
+    // Without trailing labels
+    assert_eq!(
+        ObjectIdentifier::parse_prefix_numeric(EXAMPLE_OID_2_STR),
+        Ok((EXAMPLE_OID_2, ""))
+    );
+
+    // Invalid prefixes
+    assert_eq!(
+        ObjectIdentifier::parse_prefix_numeric("commonName"),
+        Err(Error::DigitExpected { actual: b'c' })
+    );
+    assert_eq!(
+        ObjectIdentifier::parse_prefix_numeric("2.5.4. (commonName)"),
+        Err(Error::TrailingDot)
+    );
+    assert_eq!(
+        ObjectIdentifier::parse_prefix_numeric(""),
+        Err(Error::Empty)
+    );
+}
+
+#[test]
+fn from_ascii_iter() {
+    let mut input = "1.2.840".bytes();
+    let oid1 = ObjectIdentifier::from_ascii_iter(core::iter::from_fn(|| input.next())).unwrap();
+    assert_eq!(oid1, oid("1.2.840"));
+
+    for s in [EXAMPLE_OID_0_STR, EXAMPLE_OID_1_STR, EXAMPLE_OID_2_STR] {
+        assert_eq!(
+            ObjectIdentifier::from_ascii_iter(s.bytes()).unwrap(),
+            oid(s)
+        );
+    }
+}
+
+#[test]
+fn from_ascii_iter_invalid() {
+    let parse = |s: &str| ObjectIdentifier::from_ascii_iter(s.bytes());
+
+    assert_eq!(parse(""), Err(Error::Empty));
+    assert_eq!(parse("1.2."), Err(Error::TrailingDot));
+    assert_eq!(parse(".1.2"), Err(Error::LeadingDot));
+    assert_eq!(parse("1..2"), Err(Error::DigitExpected { actual: b'.' }));
+    assert_eq!(parse("1.2.a"), Err(Error::DigitExpected { actual: b'a' }));
+    assert_eq!(parse("3.2.1"), Err(Error::ArcInvalid { arc: 3 }));
+    assert_eq!(parse("1.2.4294967296"), Err(Error::ArcTooBig));
+}
+
+#[test]
+fn join() {
+    let base = oid("1.2.840");
+    assert_eq!(
+        ObjectIdentifier::join(base, "113549.1.1").unwrap(),
+        oid("1.2.840.113549.1.1")
+    );
+    assert_eq!(ObjectIdentifier::join(base, "1").unwrap(), oid("1.2.840.1"));
+}
+
+#[test]
+fn join_invalid() {
+    let base = oid("1.2.840");
+    assert_eq!(ObjectIdentifier::join(base, ""), Err(Error::Empty));
+    assert_eq!(
+        ObjectIdentifier::join(base, ".113549"),
+        Err(Error::LeadingDot)
+    );
+    assert_eq!(
+        ObjectIdentifier::join(base, "113549.1."),
+        Err(Error::TrailingDot)
+    );
+    assert_eq!(
+        ObjectIdentifier::join(base, "113549..1"),
+        Err(Error::DigitExpected { actual: b'.' })
+    );
+    assert_eq!(
+        ObjectIdentifier::join(base, "113549.x"),
+        Err(Error::DigitExpected { actual: b'x' })
+    );
+    assert_eq!(
+        ObjectIdentifier::join(base, "4294967296"),
+        Err(Error::ArcTooBig)
+    );
+}
+
+#[test]
+fn push_arc_str() {
+    let base = oid("1.2.840");
+    assert_eq!(base.push_arc_str("113549").unwrap(), oid("1.2.840.113549"));
+    assert_eq!(
+        base.push_arc_str("4294967295").unwrap(),
+        oid("1.2.840.4294967295")
+    );
+
+    assert_eq!(base.push_arc_str(""), Err(Error::Empty));
+    assert_eq!(
+        base.push_arc_str("abc"),
+        Err(Error::DigitExpected { actual: b'a' })
+    );
+    assert_eq!(
+        base.push_arc_str("1.2"),
+        Err(Error::DigitExpected { actual: b'.' })
+    );
+    assert_eq!(
+        base.push_arc_str("-1"),
+        Err(Error::DigitExpected { actual: b'-' })
+    );
+    assert_eq!(base.push_arc_str("4294967296"), Err(Error::ArcTooBig));
+
+    // Out of space
+    let full = ObjectIdentifier::from_arcs([1, 2].into_iter().chain([u32::MAX; 7])).unwrap();
+    assert_eq!(full.push_arc_str("4294967295"), Err(Error::Length));
+}
+
+#[test]
+fn push_arc_at_capacity() {
+    // 1 byte of root arcs + 7 maximally sized arcs + 2 single byte arcs = 38 bytes
+    let arcs = [1, 2].into_iter().chain([u32::MAX; 7]).chain([1, 1]);
+    let almost_full = ObjectIdentifier::from_arcs(arcs).unwrap();
+    assert_eq!(almost_full.as_bytes().len(), 38);
+
+    // Pushing a multi-byte arc onto the last free byte fails cleanly
+    for arc in [0x80, 0x4000, 0x200000, 0x10000000, u32::MAX] {
+        assert_eq!(almost_full.push_arc(arc), Err(Error::Length));
+    }
+
+    // A single byte arc fills the buffer exactly
+    let full = almost_full.push_arc(0x7F).unwrap();
+    assert_eq!(full.as_bytes().len(), ObjectIdentifier::MAX_SIZE);
+    assert_eq!(full.parent(), Some(almost_full));
+    assert_eq!(full.arcs().last(), Some(0x7F));
+    assert_eq!(full.push_arc(0), Err(Error::Length));
+}
+
+#[test]
+fn try_from_ref_beyond_u8_length() {
+    let ber = [[0x2A].as_slice(), &[0x01; 255]].concat();
+    let oid_ref = const_oid::ObjectIdentifierRef::from_bytes(&ber).unwrap();
+    assert_eq!(
+        ObjectIdentifier::<300>::try_from(oid_ref),
+        Err(Error::Length)
+    );
+}
+
+#[test]
+fn try_push_arc() {
+    let parent = oid("1.2.3");
+    assert_eq!(parent.try_push_arc(4).unwrap(), oid("1.2.3.4"));
+    assert_eq!(parent, oid("1.2.3"));
+
+    // 1 byte of root arcs + 7 maximally sized arcs = 36 bytes
+    let full = ObjectIdentifier::from_arcs([1, 2].into_iter().chain([u32::MAX; 7])).unwrap();
+    assert_eq!(full.try_push_arc(u32::MAX), Err(Error::Length));
+
+    // The original is still available to retry with a smaller arc
+    let child = full.try_push_arc(1).unwrap();
+    assert_eq!(child.parent(), Some(full));
+}
+
+#[test]
+fn push_arc_mut() {
+    let mut oid = oid("1.2");
+
+    for arc in [840, 10045, 2, 1] {
+        oid.push_arc_mut(arc).unwrap();
+    }
+
+    assert_eq!(oid, EXAMPLE_OID_1);
+}
+
+#[test]
+fn push_arc_mut_at_capacity() {
+    // 1 byte of root arcs + 7 maximally sized arcs = 36 bytes
+    let full = ObjectIdentifier::from_arcs([1, 2].into_iter().chain([u32::MAX; 7])).unwrap();
+    let mut oid = full;
+
+    assert_eq!(oid.push_arc_mut(u32::MAX), Err(Error::Length));
+    assert_eq!(oid, full);
+
+    // Remaining capacity can still be used
+    for _ in 0..3 {
+        oid.push_arc_mut(1).unwrap();
+    }
+
+    assert_eq!(oid.as_bytes().len(), ObjectIdentifier::MAX_SIZE);
+    assert_eq!(oid.push_arc_mut(1), Err(Error::Length));
+    assert_eq!(oid.arcs().count(), 12);
+}
+
+#[test]
+fn append_snmp_index() {
+    // `ipNetToMediaPhysAddress` (RFC 1213), indexed by `ipNetToMediaIfIndex` and
+    // `ipNetToMediaNetAddress`
+    let column = oid("1.3.6.1.2.1.4.22.1.2");
+    assert_eq!(
+        column.append_snmp_index(&[3, 192, 168, 0, 1]),
+        Ok(oid("1.3.6.1.2.1.4.22.1.2.3.192.168.0.1"))
+    );
+
+    // `ifDescr` for the two-element index `1.3`
+    let if_descr = oid("1.3.6.1.2.1.2.2.1.2");
+    assert_eq!(
+        if_descr.append_snmp_index(&[1, 3]),
+        Ok(oid("1.3.6.1.2.1.2.2.1.2.1.3"))
+    );
+    assert_eq!(if_descr.append_snmp_index(&[]), Ok(if_descr));
+
+    assert_eq!(
+        if_descr.append_snmp_index(&[u32::MAX; 8]),
+        Err(Error::Length)
+    );
+}
+
+#[test]
+fn hash() {
+    use std::{
+        collections::{hash_map::DefaultHasher, HashMap},
+        hash::{Hash, Hasher},
+    };
+
+    fn hash_of<T: Hash + ?Sized>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let small = ObjectIdentifier::<16>::try_from(EXAMPLE_OID_1.as_oid_ref()).unwrap();
+    assert_eq!(hash_of(&EXAMPLE_OID_1), hash_of(&oid(EXAMPLE_OID_1_STR)));
+    assert_eq!(hash_of(&EXAMPLE_OID_1), hash_of(&small));
+    assert_eq!(hash_of(&EXAMPLE_OID_1), hash_of(EXAMPLE_OID_1.as_oid_ref()));
+    assert_ne!(hash_of(&EXAMPLE_OID_1), hash_of(&EXAMPLE_OID_2));
+
+    // Lookups through `Borrow<ObjectIdentifierRef>` rely on consistent hashing
+    let mut map = HashMap::new();
+    map.insert(EXAMPLE_OID_1, "ecPublicKey");
+    assert_eq!(map.get(EXAMPLE_OID_1.as_oid_ref()), Some(&"ecPublicKey"));
+}
+
+#[test]
+fn from_der() {
+    let der = hex!("06072A8648CE3D0201");
+    assert_eq!(ObjectIdentifier::from_der(&der), Ok(EXAMPLE_OID_1));
+
+    // Valid OID followed by garbage
+    let with_garbage = hex!("06072A8648CE3D0201FFFF");
+    assert_eq!(
+        ObjectIdentifier::from_der(&with_garbage),
+        Err(Error::TrailingData)
+    );
+    assert_eq!(Error::TrailingData.kind(), ErrorKind::Encoding);
+
+    // Malformed elements
+    assert_eq!(
+        ObjectIdentifier::from_der(&hex!("04072A8648CE3D0201")),
+        Err(Error::TagInvalid { actual: 0x04 })
+    );
+    assert_eq!(
+        ObjectIdentifier::from_der(&hex!("06082A8648CE3D0201")),
+        Err(Error::Length)
+    );
+    assert_eq!(ObjectIdentifier::from_der(&[]), Err(Error::Empty));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn from_der_prefix() {
+    let with_garbage = hex!("06072A8648CE3D0201FFFF");
+    assert_eq!(
+        ObjectIdentifier::from_der_prefix(&with_garbage),
+        Ok((EXAMPLE_OID_1, hex!("FFFF").as_slice()))
+    );
+
+    // Two consecutive elements
+    let mut der = EXAMPLE_OID_1.to_der_vec();
+    der.extend_from_slice(&EXAMPLE_OID_2.to_der_vec());
+    let (first, rest) = ObjectIdentifier::from_der_prefix(&der).unwrap();
+    assert_eq!(first, EXAMPLE_OID_1);
+    assert_eq!(ObjectIdentifier::from_der(rest), Ok(EXAMPLE_OID_2));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn write_der() {
+    let mut der = Vec::new();
+    let len = EXAMPLE_OID_1.write_der(&mut der).unwrap();
+    assert_eq!(der, hex!("06072A8648CE3D0201"));
+    assert_eq!(len, EXAMPLE_OID_1.der_len());
+
+    // Values of 128 bytes or more use the long form length
+    let ber = [[0x2A].as_slice(), &[0x01; 129]].concat();
+    let large = ObjectIdentifier::<255>::try_from(
+        const_oid::ObjectIdentifierRef::from_bytes(&ber).unwrap(),
+    )
+    .unwrap();
+
+    let mut der = Vec::new();
+    assert_eq!(large.write_der(&mut der).unwrap(), 133);
+    assert_eq!(der, [[0x06, 0x81, 0x82].as_slice(), &ber].concat());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn to_der_vec() {
+    let der = oid("1.2.840.113549.1.1.1").to_der_vec();
+    assert_eq!(der, hex!("06092A864886F70D010101"));
+    assert_eq!(der.len(), oid("1.2.840.113549.1.1.1").der_len());
+
+    // Values of 128 bytes or more use the long form length
+    let ber = [[0x2A].as_slice(), &[0x01; 129]].concat();
+    let large = ObjectIdentifier::<255>::try_from(
+        const_oid::ObjectIdentifierRef::from_bytes(&ber).unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        large.to_der_vec(),
+        [[0x06, 0x81, 0x82].as_slice(), &ber].concat()
+    );
+}
+
+#[test]
+fn value_suffix_after() {
+    let base = oid("1.2.840.113549.1.1");
+    let child = oid("1.2.840.113549.1.1.11");
+    let prefix_len = base.as_bytes().len();
+
+    assert!(child.starts_with(base));
+    assert_eq!(child.value_suffix_after(prefix_len), &[11]);
+    assert_eq!(child.value_suffix_after(0), child.as_bytes());
+    assert_eq!(child.value_suffix_after(child.as_bytes().len()), &[]);
+    assert_eq!(child.value_suffix_after(usize::MAX), &[]);
+}
+
+#[test]
+fn starts_with() {
+    let child = ObjectIdentifier::new("1.2.3.4.5").unwrap();
+    assert!(child.starts_with(oid("1.2.3.4.5")));
+    assert!(child.starts_with(oid("1.2.3.4")));
+    assert!(child.starts_with(oid("1.2.3")));
+
+    assert!(!child.starts_with(oid("1.2.4")));
+    assert!(!child.starts_with(oid("2.2.3")));
+    assert!(!child.starts_with(oid("1.2.3.4.5.6")));
+}
+
+#[test]
+fn require_under() {
+    let root = oid("1.3.6.1.4.1.55555");
+    assert_eq!(oid("1.3.6.1.4.1.55555.1").require_under(root), Ok(()));
+    assert_eq!(oid("1.3.6.1.4.1.55555.1.2.3").require_under(root), Ok(()));
+
+    // The root itself is not strictly within the subtree
+    assert_eq!(root.require_under(root), Err(Error::OutsideSubtree));
+
+    // Outside the subtree, including siblings whose arc shares a leading byte
+    assert_eq!(
+        oid("1.3.6.1.4.1.55556.1").require_under(root),
+        Err(Error::OutsideSubtree)
+    );
+    assert_eq!(
+        oid("1.3.6.1.4.1").require_under(root),
+        Err(Error::OutsideSubtree)
+    );
+    assert_eq!(
+        EXAMPLE_OID_LARGE_ARC.require_under(root),
+        Err(Error::OutsideSubtree)
+    );
+    assert_eq!(Error::OutsideSubtree.kind(), ErrorKind::ArcRange);
+}
+
+#[test]
+fn encode_arcs_into_buffer() {
+    let mut out = [0u8; 16];
+    let arcs = [1, 2, 840, 10045, 2, 1];
+    let len = encode_arcs_into(&arcs, &mut out).unwrap();
+    assert_eq!(&out[..len], EXAMPLE_OID_1_BER);
+
+    // Exactly sized buffer
+    let mut out = [0u8; 9];
+    let arcs: Vec<_> = EXAMPLE_OID_2.arcs().collect();
+    assert_eq!(encode_arcs_into(&arcs, &mut out), Ok(9));
+    assert_eq!(out, EXAMPLE_OID_2_BER);
+
+    // Large second arc under the joint-iso-itu-t root
+    let len = encode_arcs_into(&[2, 999, 3], &mut out).unwrap();
+    assert_eq!(&out[..len], oid("2.999.3").as_bytes());
+}
+
+#[test]
+fn encode_arcs_into_errors() {
+    // Buffer too small, including for a multi-byte arc which only partially fits
+    assert_eq!(
+        encode_arcs_into(&[1, 2, 840, 10045, 2, 1], &mut [0u8; 6]),
+        Err(Error::Length)
+    );
+    assert_eq!(
+        encode_arcs_into(&[1, 2, 840], &mut [0u8; 2]),
+        Err(Error::Length)
+    );
+    assert_eq!(encode_arcs_into(&[1, 2, 3], &mut []), Err(Error::Length));
+
+    // Invalid arcs
+    assert_eq!(
+        encode_arcs_into(&[3, 2, 1], &mut [0u8; 8]),
+        Err(Error::ArcInvalid { arc: 3 })
+    );
+    assert_eq!(
+        encode_arcs_into(&[1, 40, 1], &mut [0u8; 8]),
+        Err(Error::ArcInvalid { arc: 40 })
+    );
+    assert_eq!(
+        encode_arcs_into(&[], &mut [0u8; 8]),
+        Err(Error::NotEnoughArcs)
+    );
+    assert_eq!(
+        encode_arcs_into(&[1], &mut [0u8; 8]),
+        Err(Error::NotEnoughArcs)
+    );
+    assert_eq!(
+        encode_arcs_into(&[1, 2], &mut [0u8; 8]),
+        Err(Error::NotEnoughArcs)
+    );
+}
+
+#[test]
+fn prefix_conflicts() {
+    let registry = [
+        oid("1.2.840.113549"),
+        oid("1.3.6.1.4.1"),
+        oid("1.2.840.113549.1.1"),
+        oid("2.5.29.15"),
+        oid("1.3.6.1.4.100"),
+        oid("1.2.840.113549.1.1.11"),
+    ];
+
+    let conflicts: Vec<_> = find_prefix_conflicts(&registry).collect();
+    assert_eq!(conflicts, [(0, 2), (0, 5), (2, 5)]);
+
+    // Non-conflicting registry
+    assert_eq!(find_prefix_conflicts(&registry[1..5]).count(), 0);
+
+    // Duplicates conflict in both directions
+    let duplicates = [EXAMPLE_OID_1, EXAMPLE_OID_2, EXAMPLE_OID_1];
+    let conflicts: Vec<_> = find_prefix_conflicts(&duplicates).collect();
+    assert_eq!(conflicts, [(0, 2), (2, 0)]);
+
+    assert_eq!(find_prefix_conflicts::<39>(&[]).count(), 0);
+}
+
+#[test]
+fn eq_str() {
+    assert_eq!(EXAMPLE_OID_1, EXAMPLE_OID_1_STR);
+    assert_eq!(EXAMPLE_OID_2, *EXAMPLE_OID_2_STR);
+    assert_eq!(oid("1.2.3").resize::<12>().unwrap(), "1.2.3");
+
+    // Unequal
+    assert_ne!(EXAMPLE_OID_1, EXAMPLE_OID_2_STR);
+    assert_ne!(EXAMPLE_OID_1, "1.2.840.10045.2");
+    assert_ne!(EXAMPLE_OID_1, "1.2.840.10045.2.1.1");
+
+    // Malformed
+    assert_ne!(EXAMPLE_OID_1, "");
+    assert_ne!(EXAMPLE_OID_1, "1.2.840.10045.2.1.");
+    assert_ne!(EXAMPLE_OID_1, "1.2.840.10045.2.x");
+    assert_ne!(EXAMPLE_OID_1, "1.2.840.10045.2.99999999999");
+    assert_ne!(EXAMPLE_OID_1, "3.2.840.10045.2.1");
+
+    // Doesn't fit in the OID's `MAX_SIZE`
+    assert_ne!(oid("1.2.3").resize::<2>().unwrap(), "1.2.3.4");
+}
+
+#[test]
+fn set_arc() {
+    // Middle arc, including one which changes the encoded length
+    assert_eq!(EXAMPLE_OID_1.set_arc(3, 7), Ok(oid("1.2.840.7.2.1")));
+    assert_eq!(EXAMPLE_OID_1.set_arc(2, 1), Ok(oid("1.2.1.10045.2.1")));
+
+    // Last arc
+    assert_eq!(
+        EXAMPLE_OID_1.set_arc(5, 99999),
+        Ok(oid("1.2.840.10045.2.99999"))
+    );
+
+    // Root arcs
+    assert_eq!(EXAMPLE_OID_1.set_arc(0, 2), Ok(oid("2.2.840.10045.2.1")));
+    assert_eq!(EXAMPLE_OID_1.set_arc(1, 39), Ok(oid("1.39.840.10045.2.1")));
+    assert_eq!(
+        EXAMPLE_OID_1.set_arc(0, 3),
+        Err(Error::ArcInvalid { arc: 3 })
+    );
+    assert_eq!(
+        EXAMPLE_OID_1.set_arc(1, 40),
+        Err(Error::ArcInvalid { arc: 40 })
+    );
+    assert_eq!(
+        oid("2.999.3").set_arc(0, 1),
+        Err(Error::ArcInvalid { arc: 999 })
+    );
+
+    // Index out of range
+    assert_eq!(EXAMPLE_OID_1.set_arc(6, 1), Err(Error::Length));
+    assert_eq!(EXAMPLE_OID_1.set_arc(usize::MAX, 1), Err(Error::Length));
+}
+
+#[test]
+fn matches_any_prefix() {
+    let child = oid("1.2.840.113549.1.1.11");
+
+    // Overlapping prefixes: the first match in slice order wins
+    let overlapping = [oid("2.5.29"), oid("1.2.840"), oid("1.2.840.113549.1.1")];
+    assert!(child.matches_any_prefix(&overlapping));
+    assert_eq!(
+        child.first_matching_prefix(&overlapping),
+        Some(&overlapping[1])
+    );
+    assert_eq!(
+        child.first_matching_prefix(&overlapping[2..]),
+        Some(&overlapping[2])
+    );
+
+    // Non-overlapping prefixes, none of which match
+    let disjoint = [
+        oid("2.5.29"),
+        oid("1.3.6.1"),
+        oid("1.2.840.113549.1.1.11.1"),
+    ];
+    assert!(!child.matches_any_prefix(&disjoint));
+    assert_eq!(child.first_matching_prefix(&disjoint), None);
+
+    // Prefixes must match on arc boundaries
+    assert!(!child.matches_any_prefix(&[oid("1.2.8")]));
+
+    assert!(!child.matches_any_prefix::<39>(&[]));
+}
+
+#[test]
+fn error_kind() {
+    assert_eq!(
+        ObjectIdentifier::new("3.2.1").unwrap_err().kind(),
+        ErrorKind::ArcRange
+    );
+    assert_eq!(
+        ObjectIdentifier::new("1.40.1").unwrap_err().kind(),
+        ErrorKind::ArcRange
+    );
+    assert_eq!(
+        ObjectIdentifier::from_bytes(&hex!("2A8FFFFFFFFF7F"))
+            .unwrap_err()
+            .kind(),
+        ErrorKind::Overflow
+    );
+    assert_eq!(
+        ObjectIdentifier::from_bytes(&hex!("2A86"))
+            .unwrap_err()
+            .kind(),
+        ErrorKind::Encoding
+    );
+    assert_eq!(
+        ObjectIdentifier::new("1.2.X").unwrap_err().kind(),
+        ErrorKind::Parse
+    );
+    assert_eq!(
+        ObjectIdentifier::new("1.2.").unwrap_err().kind(),
+        ErrorKind::Parse
+    );
+    assert_eq!(
+        ObjectIdentifier::new("").unwrap_err().kind(),
+        ErrorKind::Length
+    );
+    assert_eq!(
+        ObjectIdentifier::from_arcs([1, 2].into_iter().chain([u32::MAX; 8]))
+            .unwrap_err()
+            .kind(),
+        ErrorKind::Length
+    );
+}
+
+#[test]
+fn sequence_from_der() {
+    // Extended key usage: `id-kp-serverAuth`, `id-kp-clientAuth`
+    let der = hex!("3014" "06082B06010505070301" "06082B06010505070302");
+    let oids = ObjectIdentifier::sequence_from_der(&der)
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(oids, [oid("1.3.6.1.5.5.7.3.1"), oid("1.3.6.1.5.5.7.3.2")]);
+
+    // Empty sequence
+    assert_eq!(
+        ObjectIdentifier::sequence_from_der(&hex!("3000"))
+            .unwrap()
+            .count(),
+        0
+    );
+
+    // Wrong outer tag
+    assert_eq!(
+        ObjectIdentifier::sequence_from_der(&hex!("310406022A03")).unwrap_err(),
+        Error::TagInvalid { actual: 0x31 }
+    );
+
+    // Outer length exceeds input
+    assert_eq!(
+        ObjectIdentifier::sequence_from_der(&hex!("300506022A03")).unwrap_err(),
+        Error::Length
+    );
+
+    // Data after the outer sequence
+    assert_eq!(
+        ObjectIdentifier::sequence_from_der(&hex!("300406022A0300")).unwrap_err(),
+        Error::TrailingData
+    );
+
+    // Malformed second element stops iteration
+    let mut iter =
+        ObjectIdentifier::sequence_from_der(&hex!("3009" "06022A03" "0403010203")).unwrap();
+    assert_eq!(iter.next(), Some(Ok(oid("1.2.3"))));
+    assert_eq!(iter.next(), Some(Err(Error::TagInvalid { actual: 0x04 })));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn der_len() {
+    const EXAMPLE_OID_1_DER_LEN: usize = EXAMPLE_OID_1.der_len();
+    assert_eq!(EXAMPLE_OID_1_DER_LEN, EXAMPLE_OID_1_BER.len() + 2);
+    assert_eq!(EXAMPLE_OID_2.der_len(), EXAMPLE_OID_2_BER.len() + 2);
+
+    // 2 (`1.2.3`) + 26 * 5 (arcs) = 132 byte value, requiring a long form length
+    const LONG: ObjectIdentifier<200> = {
+        let mut oid = match ObjectIdentifier::new_unwrap("1.2.3").resize::<200>() {
+            Ok(oid) => oid,
+            Err(_) => panic!("invalid OID"),
+        };
+
+        let mut i = 0;
+        while i < 26 {
+            oid = match oid.push_arc(u32::MAX) {
+                Ok(oid) => oid,
+                Err(_) => panic!("invalid OID"),
+            };
+            i += 1;
+        }
+
+        oid
+    };
+    const LONG_DER_LEN: usize = LONG.der_len();
+    assert_eq!(LONG.as_bytes().len(), 132);
+    assert_eq!(LONG_DER_LEN, 135);
+}
+
+#[test]
+fn arcs_round_trip() {
+    let cases: &[(u32, &[u8])] = &[
+        (0, &hex!("00")),
+        (127, &hex!("7F")),
+        (128, &hex!("8100")),
+        (129, &hex!("8101")),
+        (16383, &hex!("FF7F")),
+        (16384, &hex!("818000")),
+        (2097151, &hex!("FFFF7F")),
+        (2097152, &hex!("81808000")),
+        (268435455, &hex!("FFFFFF7F")),
+        (268435456, &hex!("8180808000")),
+        (u32::MAX, &hex!("8FFFFFFF7F")),
+    ];
+
+    for &(arc, arc_ber) in cases {
+        let oid = ObjectIdentifier::from_arcs([1, 2, arc, 1]).unwrap();
+        assert_eq!(&oid.as_bytes()[1..oid.as_bytes().len() - 1], arc_ber);
+        assert_eq!(oid.arcs().collect::<Vec<_>>(), [1, 2, arc, 1]);
+
+        let decoded = ObjectIdentifier::from_bytes(oid.as_bytes()).unwrap();
+        assert_eq!(decoded, oid);
+        assert_eq!(ObjectIdentifier::from_arcs(decoded.arcs()).unwrap(), oid);
+    }
+}
+
+#[test]
+fn from_ber_canonical() {
+    assert_eq!(
+        ObjectIdentifier::from_ber_canonical(EXAMPLE_OID_1_BER),
+        Ok((EXAMPLE_OID_1, true))
+    );
+    assert_eq!(
+        ObjectIdentifier::from_ber_canonical(EXAMPLE_OID_LARGE_ARC_BER),
+        Ok((EXAMPLE_OID_LARGE_ARC, true))
+    );
+
+    // Non-minimal encodings of `1.2.3` and `1.2.128`, including in the first group
+    for (ber, expected) in [
+        (&hex!("2A8003")[..], "1.2.3"),
+        (&hex!("2A808003"), "1.2.3"),
+        (&hex!("2A808100"), "1.2.128"),
+        (&hex!("802A03"), "1.2.3"),
+    ] {
+        let (oid, minimal) = ObjectIdentifier::from_ber_canonical(ber).unwrap();
+        assert_eq!(oid, expected);
+        assert_eq!(oid.as_bytes(), oid.canonical_bytes().as_bytes());
+        assert!(!minimal);
+    }
+
+    // Padding which would make the input too long for an OID
+    let mut padded = hex!("2A").to_vec();
+    padded.extend_from_slice(&[0x80; 64]);
+    padded.push(0x03);
+    assert_eq!(
+        ObjectIdentifier::from_ber_canonical(&padded),
+        Ok((oid("1.2.3"), false))
+    );
+    assert_eq!(ObjectIdentifier::from_bytes(&padded), Err(Error::Length));
+
+    // Malformed input is rejected
+    assert_eq!(ObjectIdentifier::from_ber_canonical(&[]), Err(Error::Empty));
+    assert_eq!(
+        ObjectIdentifier::from_ber_canonical(&hex!("2A86")),
+        Err(Error::Base128 { offset: 1 })
+    );
+}
+
+#[test]
+fn canonical_bytes() {
+    assert_eq!(
+        EXAMPLE_OID_LARGE_ARC.canonical_bytes().as_bytes(),
+        EXAMPLE_OID_LARGE_ARC_BER
+    );
+
+    // Non-minimal encodings of `1.2.3` and `1.2.128`
+    for (ber, der) in [
+        (&hex!("2A8003")[..], &hex!("2A03")[..]),
+        (&hex!("2A808003"), &hex!("2A03")),
+        (&hex!("2A808100"), &hex!("2A8100")),
+    ] {
+        let oid = ObjectIdentifier::from_bytes(ber).unwrap();
+        assert_eq!(oid.as_bytes(), ber);
+        assert_eq!(oid.canonical_bytes().as_bytes(), der);
+        assert_eq!(
+            ObjectIdentifier::from_arcs(oid.arcs()).unwrap().as_bytes(),
+            der
+        );
+    }
+}
+
+#[test]
+fn new_ok() {
+    assert_eq!(
+        ObjectIdentifier::new_ok(EXAMPLE_OID_1_STR),
+        Some(EXAMPLE_OID_1)
+    );
+    assert_eq!(ObjectIdentifier::new_ok("1.2.X"), None);
+}
+
+#[test]
+fn new_or() {
+    assert_eq!(
+        ObjectIdentifier::new_or(EXAMPLE_OID_1_STR, EXAMPLE_OID_2),
+        EXAMPLE_OID_1
+    );
+    assert_eq!(
+        ObjectIdentifier::new_or("1.2.840.", EXAMPLE_OID_2),
+        EXAMPLE_OID_2
+    );
+    assert_eq!(ObjectIdentifier::new_or("", EXAMPLE_OID_2), EXAMPLE_OID_2);
+}
+
+#[test]
+fn contains_arc() {
+    assert!(EXAMPLE_OID_1.contains_arc(840));
+    assert!(EXAMPLE_OID_1.contains_arc(10045));
+    assert!(!EXAMPLE_OID_1.contains_arc(3));
+
+    // First two arcs are decoded from the combined first byte (`0x2A`)
+    assert!(EXAMPLE_OID_1.contains_arc(1));
+    assert!(EXAMPLE_OID_1.contains_arc(2));
+    assert!(!EXAMPLE_OID_1.contains_arc(0x2A));
+}
+
+#[test]
+fn parse_with_report() {
+    assert_eq!(
+        ObjectIdentifier::parse_with_report(EXAMPLE_OID_1_STR),
+        Ok(EXAMPLE_OID_1)
+    );
+
+    let report = ObjectIdentifier::parse_with_report("1.2.8x0").unwrap_err();
+    assert_eq!(report.error, Error::DigitExpected { actual: b'x' });
+    assert_eq!(
+        report.to_string(),
+        "arc contains non-digit at 5..6: expected digit, found 'x'"
+    );
+
+    let report = ObjectIdentifier::parse_with_report("5.1.2").unwrap_err();
+    assert_eq!(
+        report.to_string(),
+        "first arc is out of range at 0..1: expected 0, 1, or 2"
+    );
+}
+
+#[test]
+fn from_components() {
+    const OID: ObjectIdentifier = match ObjectIdentifier::from_components(1, 2, &[840, 10045, 2, 1])
+    {
+        Ok(oid) => oid,
+        Err(_) => panic!("invalid OID"),
+    };
+    assert_eq!(OID, EXAMPLE_OID_1);
+
+    assert_eq!(
+        ObjectIdentifier::from_components(2, 16, &[840, 1, 101, 3, 4, 1, 42]),
+        Ok(EXAMPLE_OID_2)
+    );
+    assert_eq!(
+        ObjectIdentifier::from_components(0, 39, &[1]),
+        ObjectIdentifier::new("0.39.1")
+    );
+    assert_eq!(
+        ObjectIdentifier::from_components(2, 999, &[3]),
+        ObjectIdentifier::new("2.999.3")
+    );
+
+    // Invalid first arc
+    assert_eq!(
+        ObjectIdentifier::from_components(3, 2, &[1]),
+        Err(Error::ArcInvalid { arc: 3 })
+    );
+
+    // Invalid second arc
+    assert_eq!(
+        ObjectIdentifier::from_components(1, 40, &[1]),
+        Err(Error::ArcInvalid { arc: 40 })
+    );
+    assert_eq!(
+        ObjectIdentifier::from_components(2, u32::MAX, &[1]),
+        Err(Error::ArcTooBig)
+    );
+
+    // Too long
+    assert_eq!(
+        ObjectIdentifier::from_components(1, 2, &[u32::MAX; 8]),
+        Err(Error::Length)
+    );
+}
+
+#[test]
+fn arcs_eq() {
+    // Non-minimal BER encoding of `1.2.840.10045.2.1`
+    let ber = ObjectIdentifier::from_bytes(&hex!("2A808648CE3D0201")).unwrap();
+    assert_ne!(ber, EXAMPLE_OID_1);
+    assert!(ber.arcs_eq(&EXAMPLE_OID_1));
+    assert!(EXAMPLE_OID_1.arcs_eq(&ber));
+
+    assert!(EXAMPLE_OID_1.arcs_eq(&EXAMPLE_OID_1));
+    assert!(!EXAMPLE_OID_1.arcs_eq(&EXAMPLE_OID_2));
+    assert!(!EXAMPLE_OID_1.arcs_eq(&oid("1.2.840.10045.2")));
+}
+
+#[test]
+fn arcs_eq_slice() {
+    // Equal
+    assert!(EXAMPLE_OID_1.arcs_eq_slice(&[1, 2, 840, 10045, 2, 1]));
+    assert!(EXAMPLE_OID_2.arcs_eq_slice(&[2, 16, 840, 1, 101, 3, 4, 1, 42]));
+
+    // Non-minimal BER encodings are compared by value
+    let ber = ObjectIdentifier::from_bytes(&hex!("2A808648CE3D0201")).unwrap();
+    assert!(ber.arcs_eq_slice(&[1, 2, 840, 10045, 2, 1]));
+
+    // Prefixes in either direction
+    assert!(!EXAMPLE_OID_1.arcs_eq_slice(&[1, 2, 840, 10045, 2]));
+    assert!(!EXAMPLE_OID_1.arcs_eq_slice(&[1, 2, 840, 10045, 2, 1, 0]));
+
+    // Length mismatches and differing arcs
+    assert!(!EXAMPLE_OID_1.arcs_eq_slice(&[]));
+    assert!(!EXAMPLE_OID_1.arcs_eq_slice(&[1, 2]));
+    assert!(!EXAMPLE_OID_1.arcs_eq_slice(&[1, 2, 840, 10045, 2, 2]));
+}
+
+#[test]
+fn display_alternate() {
+    assert_eq!(format!("{}", EXAMPLE_OID_1), EXAMPLE_OID_1_STR);
+    assert_eq!(
+        format!("{:#}", EXAMPLE_OID_1),
+        "1[0].2[1].840[2].10045[3].2[4].1[5]"
+    );
+    assert_eq!(
+        format!("{:#}", EXAMPLE_OID_1.as_oid_ref()),
+        "1[0].2[1].840[2].10045[3].2[4].1[5]"
+    );
+}
+
+#[test]
+fn byte_groups() {
+    let groups = EXAMPLE_OID_0.byte_groups().collect::<Vec<_>>();
+    assert_eq!(
+        groups,
+        [
+            &hex!("09")[..],
+            &hex!("9226"),
+            &hex!("8993F22C"),
+            &hex!("64"),
+            &hex!("01"),
+            &hex!("01")
+        ]
+    );
+    assert_eq!(groups.concat(), EXAMPLE_OID_0_BER);
+
+    for oid in [EXAMPLE_OID_1, EXAMPLE_OID_2] {
+        assert_eq!(oid.byte_groups().count(), oid.len() - 1);
+        assert_eq!(
+            oid.byte_groups().collect::<Vec<_>>().concat(),
+            oid.as_bytes()
+        );
+    }
+}
+
+#[test]
+fn map_tail_arcs() {
+    assert_eq!(
+        EXAMPLE_OID_1.map_tail_arcs(|_, _| 0).unwrap(),
+        oid("1.2.0.0.0.0")
+    );
+    assert_eq!(
+        EXAMPLE_OID_1.map_tail_arcs(|i, arc| if i == 3 { 0 } else { arc }),
+        Ok(oid("1.2.840.0.2.1"))
+    );
+
+    assert_eq!(
+        EXAMPLE_OID_LARGE_ARC.map_tail_arcs(|_, _| u32::MAX),
+        Ok(oid(
+            "0.9.4294967295.4294967295.4294967295.4294967295.4294967295"
+        ))
+    );
+
+    // Mapped arcs which don't fit are rejected
+    assert_eq!(
+        oid("1.2.3.4.5.6.7.8.9.10").map_tail_arcs(|_, _| u32::MAX),
+        Err(Error::Length)
+    );
+}
+
+#[test]
+fn first_and_second_arc() {
+    assert_eq!(EXAMPLE_OID_0.first_arc(), 0);
+    assert_eq!(EXAMPLE_OID_0.second_arc(), 9);
+
+    assert_eq!(EXAMPLE_OID_1.first_arc(), 1);
+    assert_eq!(EXAMPLE_OID_1.second_arc(), 2);
+
+    assert_eq!(EXAMPLE_OID_2.first_arc(), 2);
+    assert_eq!(EXAMPLE_OID_2.second_arc(), 16);
+
+    let max = oid("2.39.1");
+    assert_eq!(max.first_arc(), 2);
+    assert_eq!(max.second_arc(), 39);
+
+    // The first two arcs span multiple bytes under root 2
+    let large = oid("2.999.1");
+    assert_eq!(large.as_bytes()[..2], hex!("8837"));
+    assert_eq!(large.first_arc(), 2);
+    assert_eq!(large.second_arc(), 999);
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn zeroize() {
+    use zeroize::Zeroize;
+
+    let mut oid = EXAMPLE_OID_1;
+    oid.zeroize();
+    assert!(oid.as_bytes().is_empty());
+}
+
+#[test]
+fn root_authority() {
+    assert_eq!(EXAMPLE_OID_0.root_authority(), RootAuthority::ItuT);
+    assert_eq!(EXAMPLE_OID_0.root_authority().to_string(), "itu-t");
+
+    assert_eq!(EXAMPLE_OID_1.root_authority(), RootAuthority::Iso);
+    assert_eq!(EXAMPLE_OID_1.root_authority().to_string(), "iso");
+
+    assert_eq!(EXAMPLE_OID_2.root_authority(), RootAuthority::JointIsoItuT);
+    assert_eq!(
+        EXAMPLE_OID_2.root_authority().to_string(),
+        "joint-iso-itu-t"
+    );
+
+    assert_eq!(RootAuthority::from_arc(3), None);
+    assert_eq!(RootAuthority::Iso.arc(), 1);
+}
+
+#[test]
+fn fold_arcs() {
+    // 1 + 2 + 840 + 10045 + 2 + 1
+    let sum = EXAMPLE_OID_1.fold_arcs(0u64, |acc, arc| acc + u64::from(arc));
+    assert_eq!(sum, 10891);
+
+    let max = EXAMPLE_OID_0.fold_arcs(0, |acc, arc| acc.max(arc));
+    assert_eq!(max, 19200300);
+
+    let count = EXAMPLE_OID_2.fold_arcs(0, |acc, _| acc + 1);
+    assert_eq!(count, EXAMPLE_OID_2.len());
+}
+
+#[test]
+fn arcs_clone() {
+    let mut arcs = EXAMPLE_OID_1.arcs();
+    assert_eq!(arcs.next(), Some(1));
+    assert_eq!(arcs.next(), Some(2));
+
+    // Look ahead without consuming
+    let lookahead = arcs.clone();
+    assert!(lookahead.eq([840, 10045, 2, 1]));
+
+    assert_eq!(arcs.next(), Some(840));
+    let mut rest = arcs.clone();
+    assert_eq!(arcs.next(), Some(10045));
+    assert_eq!(arcs.next(), Some(2));
+    assert_eq!(arcs.next(), Some(1));
+    assert_eq!(arcs.next(), None);
+
+    // The clone is unaffected by the original reaching the end
+    assert_eq!(rest.next(), Some(10045));
+    assert!(rest.eq([2, 1]));
+}
+
+#[test]
+fn arcs_size_hint() {
+    // 6 arcs encoded as 7 bytes
+    let mut arcs = EXAMPLE_OID_1.arcs();
+    assert_eq!(arcs.size_hint(), (2, Some(8)));
+    arcs.next();
+    assert_eq!(arcs.size_hint(), (1, Some(7)));
+    arcs.next();
+    assert_eq!(arcs.size_hint(), (1, Some(6)));
+
+    for _ in 0..4 {
+        arcs.next();
+    }
+    assert_eq!(arcs.size_hint(), (0, Some(0)));
+
+    // The hint bounds the remaining arcs at every position
+    for oid in [
+        EXAMPLE_OID_0,
+        EXAMPLE_OID_1,
+        EXAMPLE_OID_2,
+        EXAMPLE_OID_LARGE_ARC,
+    ] {
+        let mut arcs = oid.arcs();
+
+        loop {
+            let (lower, upper) = arcs.size_hint();
+            let remaining = arcs.clone().count();
+            assert!(lower <= remaining && remaining <= upper.unwrap(), "{}", oid);
+
+            if arcs.next().is_none() {
+                break;
+            }
+        }
+    }
+}
+
+#[test]
+fn arcs_enumerated() {
+    let arcs: Vec<_> = EXAMPLE_OID_2.arcs_enumerated().collect();
+    assert_eq!(arcs.len(), EXAMPLE_OID_2.len());
+
+    for (expected, &(i, arc, is_last)) in EXAMPLE_OID_2.arcs().zip(&arcs) {
+        assert_eq!(arc, expected);
+        assert_eq!(is_last, i == arcs.len() - 1);
+    }
+
+    assert_eq!(arcs.iter().filter(|(_, _, is_last)| *is_last).count(), 1);
+    assert_eq!(arcs.last(), Some(&(8, 42, true)));
+    assert_eq!(arcs.first(), Some(&(0, 2, false)));
+}
+
+#[test]
+fn arc_or() {
+    // 1.2.840.10045.2.1
+    assert_eq!(EXAMPLE_OID_1.arc_or(0, 99), 1);
+    assert_eq!(EXAMPLE_OID_1.arc_or(3, 99), 10045);
+    assert_eq!(EXAMPLE_OID_1.arc_or(5, 99), 1);
+
+    // Absent
+    assert_eq!(EXAMPLE_OID_1.arc_or(6, 99), 99);
+    assert_eq!(EXAMPLE_OID_1.arc_or(usize::MAX, 0), 0);
+}
+
+#[test]
+fn validate_arc_bounds() {
+    // 0.9.2342.19200300.100.1.1
+    assert_eq!(EXAMPLE_OID_LARGE_ARC.validate_arc_bounds(Arc::MAX), Ok(()));
+    assert_eq!(EXAMPLE_OID_LARGE_ARC.validate_arc_bounds(19200300), Ok(()));
+    assert_eq!(
+        EXAMPLE_OID_LARGE_ARC.validate_arc_bounds(19200299),
+        Err(Error::ArcOutOfBounds {
+            index: 3,
+            arc: 19200300
+        })
+    );
+
+    // The first offending arc is reported
+    assert_eq!(
+        EXAMPLE_OID_LARGE_ARC.validate_arc_bounds(255),
+        Err(Error::ArcOutOfBounds {
+            index: 2,
+            arc: 2342
+        })
+    );
+    assert_eq!(
+        EXAMPLE_OID_1.validate_arc_bounds(1),
+        Err(Error::ArcOutOfBounds { index: 1, arc: 2 })
+    );
+    assert_eq!(
+        Error::ArcOutOfBounds { index: 1, arc: 2 }.kind(),
+        ErrorKind::ArcRange
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn to_components() {
+    let (first, second, rest) = oid("2.999.1.2").to_components();
+    assert_eq!(first, 2);
+    assert_eq!(second, 999);
+    assert_eq!(rest, [1, 2]);
+
+    // Round trips through `from_components`
+    let (first, second, rest) = EXAMPLE_OID_1.to_components();
+    assert_eq!((first, second), (1, 2));
+    assert_eq!(rest, [840, 10045, 2, 1]);
+    assert_eq!(
+        ObjectIdentifier::from_components(first, second, &rest),
+        Ok(EXAMPLE_OID_1)
+    );
+
+    let (first, second, rest) = oid("2.999.1.2").to_components();
+    assert_eq!(
+        ObjectIdentifier::from_components(first, second, &rest),
+        Ok(oid("2.999.1.2"))
+    );
+
+    // Only the required arcs
+    assert_eq!(oid("0.9.1").to_components(), (0, 9, vec![1]));
+}
+
+#[test]
+fn arcs_from() {
+    for oid in [EXAMPLE_OID_0, EXAMPLE_OID_1, EXAMPLE_OID_2] {
+        assert!(oid.arcs_from(2).eq(oid.arcs().skip(2)));
+        assert!(oid.arcs_from(0).eq(oid.arcs()));
+        assert_eq!(oid.arcs_from(oid.len()).next(), None);
+    }
+
+    assert!(EXAMPLE_OID_1.arcs_from(2).eq([840, 10045, 2, 1]));
+    assert_eq!(EXAMPLE_OID_1.arcs_from(usize::MAX).next(), None);
+}
+
+#[test]
+fn eq_bytes() {
+    // Slices, arrays, and vectors
+    assert!(EXAMPLE_OID_1.eq_bytes(EXAMPLE_OID_1_BER));
+    assert!(EXAMPLE_OID_1.eq_bytes(hex!("2A8648CE3D0201")));
+    let vec = hex!("2A8648CE3D0201").to_vec();
+    assert!(EXAMPLE_OID_1.eq_bytes(&vec));
+    assert!(EXAMPLE_OID_1.eq_bytes(vec));
+    assert!(EXAMPLE_OID_1.as_oid_ref().eq_bytes(EXAMPLE_OID_1));
+
+    assert!(!EXAMPLE_OID_1.eq_bytes(EXAMPLE_OID_2_BER));
+    assert!(!EXAMPLE_OID_1.eq_bytes(&EXAMPLE_OID_1_BER[..6]));
+    assert!(!EXAMPLE_OID_1.eq_bytes(hex!("06072A8648CE3D0201")));
+    assert!(!EXAMPLE_OID_1.eq_bytes([]));
+}
+
+#[test]
+fn crc32_arcs() {
+    // Matches e.g. `zlib.crc32(bytes.fromhex("2A8648CE3D0201"))`
+    assert_eq!(EXAMPLE_OID_1.crc32_arcs(), 0xA9DB_E003);
+    assert_ne!(EXAMPLE_OID_1.crc32_arcs(), EXAMPLE_OID_2.crc32_arcs());
+
+    // Independent of buffer size
+    let small = EXAMPLE_OID_1.resize::<8>().unwrap();
+    let large = EXAMPLE_OID_1.resize::<64>().unwrap();
+    assert_eq!(small.crc32_arcs(), EXAMPLE_OID_1.crc32_arcs());
+    assert_eq!(large.crc32_arcs(), EXAMPLE_OID_1.crc32_arcs());
+}
+
+#[test]
+fn sort_key() {
+    assert_eq!(
+        EXAMPLE_OID_1.sort_key::<16>(),
+        hex!("0101 0102 020348 02273D 0102 0101 0000")
+    );
+
+    let mut oids = [
+        "2.999.1",
+        "1.2.840.10045.2.1",
+        "1.2.840.10045",
+        "1.2.840.113549.1.1.1",
+        "1.2.840.113549.1.1",
+        "1.2.3.4294967295",
+        "1.2.3.0",
+        "1.2.3",
+        "0.9.2342.19200300.100.1.1",
+        "1.3.6.1.4.1.311.21.20",
+        "1.2.256",
+        "1.2.255.1",
+    ]
+    .map(oid);
+
+    oids.sort_by(|a, b| a.arcs().cmp(b.arcs()));
+
+    for pair in oids.windows(2) {
+        assert!(
+            pair[0].sort_key::<48>() < pair[1].sort_key::<48>(),
+            "{} < {}",
+            pair[0],
+            pair[1]
+        );
+
+        // Truncated keys preserve the order, but not uniqueness
+        assert!(pair[0].sort_key::<6>() <= pair[1].sort_key::<6>());
+    }
+
+    // Independent of buffer size
+    let large = EXAMPLE_OID_1.resize::<64>().unwrap();
+    assert_eq!(large.sort_key::<32>(), EXAMPLE_OID_1.sort_key::<32>());
+}
+
+#[test]
+fn error_display() {
+    let cases = [
+        (Error::ArcInvalid { arc: 40 }, &["40", "0-2", "0-39"][..]),
+        (
+            Error::ArcOutOfBounds {
+                index: 3,
+                arc: 70000,
+            },
+            &["70000", "index 3", "maximum"],
+        ),
+        (Error::ArcTooBig, &["32-bits", "4294967295"]),
+        (Error::Base128 { offset: 6 }, &["base 128", "offset 6"]),
+        (Error::CborInvalid, &["CBOR", "tag 111"]),
+        (Error::DigitExpected { actual: b'x' }, &["digit", "'x'"]),
+        (Error::DigitExpected { actual: 0xC3 }, &["digit", "0xC3"]),
+        (Error::Empty, &["empty"]),
+        (Error::LabelInvalid, &["label", "number", "name"]),
+        (Error::LeadingDot, &["leading '.'", "digit"]),
+        (Error::Length, &["length", "255"]),
+        (Error::NotEnoughArcs, &["at least 3 arcs", "1.2.3"]),
+        (Error::OutsideSubtree, &["outside", "subtree"]),
+        (Error::TagInvalid { actual: 0x04 }, &["0x04", "0x06"]),
+        (Error::TooFewArcs, &["fewer arcs", "expected"]),
+        (Error::TooManyArcs, &["more arcs", "limit"]),
+        (Error::TrailingData, &["trailing data"]),
+        (Error::TrailingDot, &["trailing '.'"]),
+    ];
+
+    for (error, fragments) in cases {
+        let message = error.to_string();
+
+        for fragment in fragments {
+            assert!(message.contains(fragment), "{:?}: {}", error, message);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn wrapped_error() {
+    use std::{error::Error as _, fmt};
+
+    /// Application error embedding `const_oid::Error`, as e.g. `thiserror` would generate.
+    #[derive(Debug)]
+    enum AppError {
+        Oid(Error),
+    }
+
+    impl fmt::Display for AppError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                AppError::Oid(_) => f.write_str("invalid algorithm identifier"),
+            }
+        }
+    }
+
+    impl std::error::Error for AppError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            match self {
+                AppError::Oid(err) => Some(err),
+            }
+        }
+    }
+
+    impl From<Error> for AppError {
+        fn from(err: Error) -> Self {
+            AppError::Oid(err)
+        }
+    }
+
+    fn parse(s: &str) -> Result<ObjectIdentifier, AppError> {
+        Ok(ObjectIdentifier::new(s)?)
+    }
+
+    let err = parse("1.2.").unwrap_err();
+    assert_eq!(err.to_string(), "invalid algorithm identifier");
+
+    let source = err.source().unwrap();
+    assert_eq!(source.to_string(), Error::TrailingDot.to_string());
+    assert_eq!(source.downcast_ref::<Error>(), Some(&Error::TrailingDot));
+
+    // Conversions from richer errors and into other error types
+    let report = ObjectIdentifier::parse_with_report("1.2.").unwrap_err();
+    assert_eq!(Error::from(report), Error::TrailingDot);
+    assert_eq!(ErrorKind::from(Error::TrailingDot), ErrorKind::Parse);
+
+    let list_err = ObjectIdentifier::parse_list("1.2.3, 1.x").unwrap_err();
+    assert_eq!(Error::from(list_err), Error::DigitExpected { actual: b'x' });
+
+    let io_err = std::io::Error::from(Error::Length);
+    assert_eq!(io_err.kind(), std::io::ErrorKind::InvalidData);
+    assert_eq!(
+        io_err.get_ref().unwrap().downcast_ref::<Error>(),
+        Some(&Error::Length)
+    );
 }
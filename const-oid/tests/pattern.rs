@@ -0,0 +1,59 @@
+//! Tests for `OidPattern`.
+
+use const_oid::{Error, ObjectIdentifier, OidPattern};
+
+/// Create an OID from a string.
+fn oid(s: &str) -> ObjectIdentifier {
+    ObjectIdentifier::new(s).unwrap()
+}
+
+#[test]
+fn exact() {
+    let pattern = OidPattern::new("1.2.840.113549").unwrap();
+    assert!(pattern.matches(&oid("1.2.840.113549")));
+    assert!(!pattern.matches(&oid("1.2.840.113549.1")));
+    assert!(!pattern.matches(&oid("1.2.840")));
+}
+
+#[test]
+fn single_wildcard() {
+    let pattern = "1.3.6.1.4.1.*.1".parse::<OidPattern>().unwrap();
+    assert!(pattern.matches(&oid("1.3.6.1.4.1.311.1")));
+    assert!(pattern.matches(&oid("1.3.6.1.4.1.11129.1")));
+    assert!(!pattern.matches(&oid("1.3.6.1.4.1.311.2")));
+    assert!(!pattern.matches(&oid("1.3.6.1.4.1.311")));
+    assert!(!pattern.matches(&oid("1.3.6.1.4.1.311.1.1")));
+}
+
+#[test]
+fn trailing_wildcard() {
+    let pattern = OidPattern::new("1.3.6.1.4.1.311.**").unwrap();
+    assert!(pattern.matches(&oid("1.3.6.1.4.1.311")));
+    assert!(pattern.matches(&oid("1.3.6.1.4.1.311.21.20")));
+    assert!(!pattern.matches(&oid("1.3.6.1.4.1.312.21")));
+
+    let pattern = OidPattern::new("2.*.840.**").unwrap();
+    assert!(pattern.matches(&oid("2.16.840.1.101.3.4")));
+    assert!(!pattern.matches(&oid("1.2.840.113549")));
+}
+
+#[test]
+fn matches_oid_ref() {
+    let pattern = OidPattern::new("1.2.*").unwrap();
+    assert!(pattern.matches(oid("1.2.3").as_oid_ref()));
+}
+
+#[test]
+fn invalid() {
+    assert_eq!(OidPattern::new(""), Err(Error::Empty));
+    assert_eq!(OidPattern::new("1.2."), Err(Error::TrailingDot));
+    assert_eq!(
+        OidPattern::new("1.**.3"),
+        Err(Error::DigitExpected { actual: b'*' })
+    );
+    assert_eq!(
+        OidPattern::new("1.2.x"),
+        Err(Error::DigitExpected { actual: b'x' })
+    );
+    assert_eq!(OidPattern::new("1.2.4294967296"), Err(Error::ArcTooBig));
+}
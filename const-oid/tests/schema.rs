@@ -0,0 +1,89 @@
+//! Tests for `OidSchema`.
+
+use const_oid::{ArcConstraint, ObjectIdentifier, OidSchema, SchemaError};
+
+/// Create an OID from a string.
+fn oid(s: &str) -> ObjectIdentifier {
+    ObjectIdentifier::new(s).unwrap()
+}
+
+/// Private enterprise arc under `1.3.6.1.4.1`, with a department and a
+/// product number.
+const ENTERPRISE: &[ArcConstraint<'static>] = &[
+    ArcConstraint::Set(&[1]),
+    ArcConstraint::Set(&[3]),
+    ArcConstraint::Set(&[6]),
+    ArcConstraint::Set(&[1]),
+    ArcConstraint::Set(&[4]),
+    ArcConstraint::Set(&[1]),
+    ArcConstraint::Any,
+    ArcConstraint::Set(&[10, 20, 30]),
+    ArcConstraint::Range { min: 1, max: 999 },
+];
+
+#[test]
+fn conforming() {
+    let schema = OidSchema::new(ENTERPRISE);
+    assert_eq!(schema.validate(&oid("1.3.6.1.4.1.311.10.1")), Ok(()));
+    assert_eq!(schema.validate(&oid("1.3.6.1.4.1.55555.30.999")), Ok(()));
+}
+
+#[test]
+fn non_conforming() {
+    let schema = OidSchema::new(ENTERPRISE);
+
+    // Not in set
+    assert_eq!(
+        schema.validate(&oid("1.3.6.1.4.1.311.11.1")),
+        Err(SchemaError::ArcRejected { index: 7, arc: 11 })
+    );
+
+    // Out of range
+    assert_eq!(
+        schema.validate(&oid("1.3.6.1.4.1.311.10.0")),
+        Err(SchemaError::ArcRejected { index: 8, arc: 0 })
+    );
+    assert_eq!(
+        schema.validate(&oid("1.3.6.1.4.1.311.10.1000")),
+        Err(SchemaError::ArcRejected {
+            index: 8,
+            arc: 1000
+        })
+    );
+
+    // Wrong root
+    assert_eq!(
+        schema.validate(&oid("2.5.29.15")),
+        Err(SchemaError::ArcRejected { index: 0, arc: 2 })
+    );
+
+    // Wrong number of arcs
+    assert_eq!(
+        schema.validate(&oid("1.3.6.1.4.1.311.10")),
+        Err(SchemaError::TooFewArcs)
+    );
+    assert_eq!(
+        schema.validate(&oid("1.3.6.1.4.1.311.10.1.1")),
+        Err(SchemaError::TooManyArcs)
+    );
+}
+
+#[test]
+fn extra_arcs() {
+    let schema = OidSchema::new(ENTERPRISE).allow_extra_arcs();
+    assert_eq!(schema.validate(&oid("1.3.6.1.4.1.311.10.1")), Ok(()));
+    assert_eq!(schema.validate(&oid("1.3.6.1.4.1.311.10.1.1.2")), Ok(()));
+    assert_eq!(
+        schema.validate(&oid("1.3.6.1.4.1.311.10")),
+        Err(SchemaError::TooFewArcs)
+    );
+}
+
+#[test]
+fn constraints() {
+    assert!(ArcConstraint::Any.allows(u32::MAX));
+    assert!(ArcConstraint::Range { min: 5, max: 5 }.allows(5));
+    assert!(!ArcConstraint::Range { min: 5, max: 5 }.allows(6));
+    assert!(!ArcConstraint::Range { min: 5, max: 4 }.allows(5));
+    assert!(!ArcConstraint::Set(&[]).allows(0));
+}
@@ -0,0 +1,61 @@
+//! Tests for `OidSet`.
+
+#![cfg(feature = "alloc")]
+
+use const_oid::{ObjectIdentifier, OidSet};
+
+/// Create an OID from a string.
+fn oid(s: &str) -> ObjectIdentifier {
+    ObjectIdentifier::new(s).unwrap()
+}
+
+fn example_set() -> OidSet {
+    [oid("1.2.840.113549.1.1"), oid("2.5.29.15")]
+        .into_iter()
+        .collect()
+}
+
+#[test]
+fn exact_match() {
+    let set = example_set();
+    assert!(set.contains_exact(&oid("1.2.840.113549.1.1")));
+    assert!(set.contains_exact(&oid("2.5.29.15")));
+    assert!(!set.contains_exact(&oid("1.2.840.113549.1.1.11")));
+    assert!(set.contains_or_ancestor(&oid("2.5.29.15")));
+}
+
+#[test]
+fn ancestor_match() {
+    let set = example_set();
+    assert!(set.contains_or_ancestor(&oid("1.2.840.113549.1.1.1")));
+    assert!(set.contains_or_ancestor(&oid("1.2.840.113549.1.1.11.7")));
+    assert!(set.contains_or_ancestor(&oid("2.5.29.15.1")));
+}
+
+#[test]
+fn non_member() {
+    let set = example_set();
+
+    // Ancestors and siblings of members, and OIDs which share a byte prefix but not an arc prefix
+    assert!(!set.contains_or_ancestor(&oid("1.2.840.113549.1")));
+    assert!(!set.contains_or_ancestor(&oid("1.2.840.113549.1.2")));
+    assert!(!set.contains_or_ancestor(&oid("2.5.29.150")));
+    assert!(!set.contains_or_ancestor(&oid("2.5.29")));
+    assert!(!OidSet::new().contains_or_ancestor(&oid("1.2.3")));
+}
+
+#[test]
+fn insert_remove() {
+    let mut set = OidSet::new();
+    assert!(set.is_empty());
+    assert!(set.insert(oid("1.2.3")));
+    assert!(!set.insert(oid("1.2.3")));
+    assert!(set.insert(oid("1.2.3.4")));
+    assert_eq!(set.len(), 2);
+
+    assert!(set.remove(&oid("1.2.3")));
+    assert!(!set.remove(&oid("1.2.3")));
+    assert!(!set.contains_or_ancestor(&oid("1.2.3.5")));
+    assert!(set.contains_or_ancestor(&oid("1.2.3.4.5")));
+    assert_eq!(set.iter().collect::<Vec<_>>(), [&oid("1.2.3.4")]);
+}
@@ -0,0 +1,45 @@
+//! Tests for OID association traits.
+
+use const_oid::{AssociatedOid, AssociatedOids, ObjectIdentifier};
+
+const ID_RSASSA_PSS: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.10");
+const ID_MGF1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.8");
+
+struct RsaPss;
+
+impl AssociatedOid for RsaPss {
+    const OID: ObjectIdentifier = ID_RSASSA_PSS;
+}
+
+struct Mgf1;
+
+impl AssociatedOid for Mgf1 {
+    const OID: ObjectIdentifier = ID_MGF1;
+}
+
+/// Algorithm identified by a legacy and a current OID.
+struct Versioned;
+
+impl AssociatedOids for Versioned {
+    const OIDS: &'static [ObjectIdentifier] = &[
+        ObjectIdentifier::new_unwrap("1.3.14.3.2.26"),
+        ObjectIdentifier::new_unwrap("1.3.14.3.2.29"),
+    ];
+}
+
+#[test]
+fn associated_oids() {
+    assert_eq!(
+        Versioned::OIDS,
+        [
+            ObjectIdentifier::new_unwrap("1.3.14.3.2.26"),
+            ObjectIdentifier::new_unwrap("1.3.14.3.2.29")
+        ]
+    );
+}
+
+#[test]
+fn associated_oids_from_associated_oid() {
+    assert_eq!(RsaPss::OIDS, [ID_RSASSA_PSS]);
+    assert_eq!(<(RsaPss, Mgf1)>::OIDS, [ID_RSASSA_PSS, ID_MGF1]);
+}
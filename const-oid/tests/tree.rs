@@ -0,0 +1,82 @@
+//! Tests for `OidTree`.
+
+#![cfg(feature = "alloc")]
+
+use const_oid::{ObjectIdentifier, OidTree};
+
+/// Create an OID from a string.
+fn oid(s: &str) -> ObjectIdentifier {
+    ObjectIdentifier::new(s).unwrap()
+}
+
+fn example_tree() -> OidTree<'static> {
+    OidTree::from_entries([
+        ("sha256WithRSAEncryption", oid("1.2.840.113549.1.1.11")),
+        ("rsadsi", oid("1.2.840.113549")),
+        ("rsaEncryption", oid("1.2.840.113549.1.1.1")),
+        ("id-ce", oid("2.5.29")),
+        ("id-ce-keyUsage", oid("2.5.29.15")),
+        ("keyUsage", oid("2.5.29.15")),
+    ])
+}
+
+#[test]
+fn walk_depth_first() {
+    let tree = example_tree();
+    let walked: Vec<_> = tree
+        .walk()
+        .map(|(depth, node)| (depth, node.arc(), node.name()))
+        .collect();
+
+    assert_eq!(
+        walked,
+        [
+            (0, 1, None),
+            (1, 2, None),
+            (2, 840, None),
+            (3, 113549, Some("rsadsi")),
+            (4, 1, None),
+            (5, 1, None),
+            (6, 1, Some("rsaEncryption")),
+            (6, 11, Some("sha256WithRSAEncryption")),
+            (0, 2, None),
+            (1, 5, None),
+            (2, 29, Some("id-ce")),
+            (3, 15, Some("id-ce-keyUsage")),
+        ]
+    );
+}
+
+#[test]
+fn children_of() {
+    let tree = example_tree();
+
+    let children: Vec<_> = tree
+        .children_of(&oid("1.2.840.113549.1.1"))
+        .unwrap()
+        .map(|node| (node.arc(), node.oid()))
+        .collect();
+
+    assert_eq!(
+        children,
+        [
+            (1, Some(oid("1.2.840.113549.1.1.1"))),
+            (11, Some(oid("1.2.840.113549.1.1.11")))
+        ]
+    );
+
+    assert_eq!(tree.children_of(&oid("2.5.29.15")).unwrap().count(), 0);
+    assert!(tree.children_of(&oid("2.5.30")).is_none());
+    assert_eq!(
+        tree.roots().map(|node| node.arc()).collect::<Vec<_>>(),
+        [1, 2]
+    );
+}
+
+#[test]
+fn empty() {
+    let tree = OidTree::from_entries([]);
+    assert_eq!(tree.walk().count(), 0);
+    assert_eq!(tree.roots().count(), 0);
+    assert!(tree.get(&oid("1.2.3")).is_none());
+}
@@ -0,0 +1,44 @@
+//! `winnow` parser combinator tests.
+
+#![cfg(feature = "winnow")]
+
+use const_oid::{parse_der, ObjectIdentifier};
+use hex_literal::hex;
+use winnow::{binary::u8, error::ContextError, Parser};
+
+const EXAMPLE_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+
+#[test]
+fn parse_der_embedded() {
+    // version byte, OID TLV, then a trailing `NULL`
+    let mut input: &[u8] = &hex!("01 06072A8648CE3D0201 0500");
+
+    let (version, oid) = (u8::<_, ContextError>, parse_der)
+        .parse_next(&mut input)
+        .unwrap();
+
+    assert_eq!(version, 1);
+    assert_eq!(oid, EXAMPLE_OID);
+    assert_eq!(input, &hex!("0500"));
+}
+
+#[test]
+fn parse_der_repeated() {
+    let input: &[u8] = &hex!("06072A8648CE3D0201 06072A8648CE3D0201");
+    let oids = (parse_der::<ContextError>, parse_der).parse(input).unwrap();
+
+    assert_eq!(oids, (EXAMPLE_OID, EXAMPLE_OID));
+}
+
+#[test]
+fn parse_der_backtracks_on_error() {
+    // wrong tag: input must be left untouched
+    let mut input: &[u8] = &hex!("0500");
+    assert!(parse_der::<ContextError>(&mut input).is_err());
+    assert_eq!(input, &hex!("0500"));
+
+    // truncated value
+    let mut input: &[u8] = &hex!("06072A8648");
+    assert!(parse_der::<ContextError>(&mut input).is_err());
+    assert_eq!(input, &hex!("06072A8648"));
+}
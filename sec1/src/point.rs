@@ -539,7 +539,7 @@ impl Tag {
     /// Compress the given y-coordinate, returning a `Tag::Compressed*` value
     fn compress_y(y: &[u8]) -> Self {
         // Is the y-coordinate odd in the SEC1 sense: `self mod 2 == 1`?
-        if y.as_ref().last().expect("empty y-coordinate") & 1 == 1 {
+        if y.last().expect("empty y-coordinate") & 1 == 1 {
             Tag::CompressedOddY
         } else {
             Tag::CompressedEvenY